@@ -0,0 +1,110 @@
+//! Compares [`Word::guess`] against the `HashMap`-based scoring it replaced,
+//! guessing every answer in the classic list against a sample of guessable words.
+//! Each version is run several times, and the median run is compared.
+//!
+//! Run with `cargo bench --features classic_words`.
+
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use kwordle::{Guess, Letter, LetterState, Word};
+
+const SAMPLE: usize = 500;
+
+/// The number of timed runs of each version.
+const RUNS: usize = 11;
+
+fn hash_map_guess(answer: Word<5>, word: Word<5>) -> Guess<5> {
+    let mut map: HashMap<Letter, usize> = HashMap::new();
+    for letter in answer.letters {
+        *map.entry(letter).or_default() += 1;
+    }
+
+    let mut states = [LetterState::NotPresent; 5];
+
+    for (i, state) in states.iter_mut().enumerate() {
+        if word.letters[i] == answer.letters[i] {
+            *state = LetterState::Correct;
+            *map.get_mut(&word.letters[i]).unwrap() -= 1;
+        }
+    }
+
+    for (i, state) in states.iter_mut().enumerate() {
+        let count = map.get_mut(&word.letters[i]);
+
+        if let Some(count) = count.filter(|count| **count > 0) {
+            if *state != LetterState::Correct {
+                *state = LetterState::WrongPlace;
+                *count -= 1;
+            }
+        }
+    }
+
+    Guess::from_states(word.letters, states)
+}
+
+/// Times `f` over several runs after a warm-up, returning the median time per guess.
+///
+/// The median is less affected by noise than a single run, and the fastest run is printed too.
+fn time(name: &str, pairs: usize, f: impl Fn()) -> f64 {
+    f();
+
+    let mut runs: Vec<_> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    runs.sort_unstable();
+
+    let (fastest, median) = (runs[0], runs[RUNS / 2]);
+    let per_guess = |elapsed: Duration| elapsed.as_secs_f64() * 1e9 / pairs as f64;
+
+    println!(
+        "{name:>10}: {median:>12.2?} median, {:>8.2} ns/guess (fastest {:.2} ns/guess)",
+        per_guess(median),
+        per_guess(fastest)
+    );
+    per_guess(median)
+}
+
+fn main() {
+    let list = kwordle::classic::words_list();
+
+    let mut guesses: Vec<_> = list.guessable.iter().copied().collect();
+    guesses.sort_unstable();
+    let step = guesses.len() / SAMPLE;
+    let guesses: Vec<_> = guesses.into_iter().step_by(step).collect();
+    let answers = list.answers.as_slice();
+
+    for guess in &guesses {
+        for answer in answers {
+            assert_eq!(answer.guess(*guess), hash_map_guess(*answer, *guess));
+        }
+    }
+
+    let pairs = guesses.len() * answers.len();
+    println!("{pairs} guesses");
+
+    let old = time("hash map", pairs, || {
+        for guess in &guesses {
+            for answer in answers {
+                black_box(hash_map_guess(black_box(*answer), black_box(*guess)));
+            }
+        }
+    });
+
+    let new = time("array", pairs, || {
+        for guess in &guesses {
+            for answer in answers {
+                black_box(black_box(*answer).guess(black_box(*guess)));
+            }
+        }
+    });
+
+    println!("{:>10}: {:.1}x", "speed-up", old / new);
+}
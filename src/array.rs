@@ -6,10 +6,14 @@ pub struct Array<T, const N: usize> {
 }
 
 impl<T, const N: usize> Array<T, N> {
-    pub fn new(array: [T; N]) -> Self {
+    pub const fn new(array: [T; N]) -> Self {
         Self { array }
     }
 
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.array
+    }
+
     pub fn from_iter<I>(iter: I) -> Result<Self, LengthError>
     where
         I: IntoIterator<Item = T>,
@@ -0,0 +1,89 @@
+//! Helpers shared by the command-line tools.
+
+// each binary includes this module, but not every binary uses every helper
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::str::FromStr;
+
+use kwordle::word::list::{answers::Answers, guessable::Guessable};
+use kwordle::WordsList;
+
+/// Calls a function generic over the word length with a length known only at runtime.
+///
+/// Evaluates to an error message if the length is not supported.
+macro_rules! dispatch_length {
+    ($length:expr, $run:ident($($arg:expr),* $(,)?)) => {
+        match $length {
+            2 => $run::<2>($($arg),*),
+            3 => $run::<3>($($arg),*),
+            4 => $run::<4>($($arg),*),
+            5 => $run::<5>($($arg),*),
+            6 => $run::<6>($($arg),*),
+            7 => $run::<7>($($arg),*),
+            8 => $run::<8>($($arg),*),
+            9 => $run::<9>($($arg),*),
+            10 => $run::<10>($($arg),*),
+            11 => $run::<11>($($arg),*),
+            12 => $run::<12>($($arg),*),
+            other => Err(format!("words of length {other} are not supported")),
+        }
+    };
+}
+
+pub(crate) use dispatch_length;
+
+pub fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn parse_number<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a number, got \"{value}\""))
+}
+
+/// Loads a [`WordsList`] from files of words, one per line,
+/// or the classic list if no guessable words are given.
+///
+/// Without an answers file, every guessable word is a possible answer.
+/// Answers that are missing from the guessable words are added to them.
+pub fn words_list<const N: usize>(
+    words: Option<&Path>,
+    answers: Option<&Path>,
+) -> Result<WordsList<N>, String> {
+    let Some(words) = words else {
+        if answers.is_some() {
+            return Err("--answers also needs --words".to_owned());
+        }
+
+        return classic();
+    };
+
+    let mut guessable = Guessable::<N>::from_str(&read(words)?)
+        .map_err(|err| format!("{}: {err}", words.display()))?;
+
+    let answers = match answers {
+        Some(path) => Answers::<N>::from_str(&read(path)?)
+            .map_err(|err| format!("{}: {err}", path.display()))?,
+        None => Answers::from_guessable(&guessable),
+    };
+
+    // `WordsList::new` needs the answers to be all in or all out of the guessable words
+    if !guessable.includes_answers(&answers) {
+        answers.append_to_guessable(&mut guessable);
+    }
+
+    Ok(WordsList::new(guessable, answers))
+}
+
+/// Uses the classic list, which only has 5-letter words.
+fn classic<const N: usize>() -> Result<WordsList<N>, String> {
+    let list: Box<dyn std::any::Any> = Box::new(kwordle::classic::words_list());
+
+    list.downcast::<WordsList<N>>()
+        .map(|list| *list)
+        .map_err(|_| {
+            format!("the classic list only has 5-letter words, so --length {N} needs --words")
+        })
+}
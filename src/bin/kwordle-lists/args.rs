@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use crate::common::parse_number;
+
+pub const USAGE: &str = "\
+usage: kwordle-lists <command> [options] <files>...
+
+Maintains word lists in the one-word-per-line format.
+
+commands:
+    validate <files>...           report bad entries with their line numbers, and duplicates
+    normalise <file>              trim, lower-case, dedupe and sort a list, skipping blank lines
+    merge <files>...              combine lists into one normalised list
+    diff <old> <new>              show words removed (-) and added (+); exits with 1 if they differ
+    subset <answers> <allowed>    check that every answer is allowed; exits with 1 if not
+    disjoint <answers> <allowed>  check that no answer is allowed; exits with 1 if any are
+
+options:
+    -l, --length <N>     letters per word (default 5)
+    -o, --output <FILE>  write the list from normalise or merge to a file instead of stdout
+    -h, --help           print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Validate,
+    Normalise,
+    Merge,
+    Diff,
+    Subset,
+    Disjoint,
+}
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub command: Option<Command>,
+    pub files: Vec<PathBuf>,
+    pub length: usize,
+    pub output: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl Args {
+    /// Parses arguments, not including the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            command: None,
+            files: Vec::new(),
+            length: 5,
+            output: None,
+            help: false,
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_owned(), Some(value.to_owned()))
+                }
+                _ => (arg, None),
+            };
+
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{flag} needs a value"))
+            };
+
+            match flag.as_str() {
+                "-l" | "--length" => parsed.length = parse_number(&flag, &value()?)?,
+                "-o" | "--output" => parsed.output = Some(value()?.into()),
+                "-h" | "--help" => parsed.help = true,
+                other if other.starts_with('-') && other != "-" => {
+                    return Err(format!("unknown option \"{other}\""))
+                }
+                _ if parsed.command.is_none() => {
+                    parsed.command = Some(match flag.as_str() {
+                        "validate" => Command::Validate,
+                        "normalise" | "normalize" => Command::Normalise,
+                        "merge" => Command::Merge,
+                        "diff" => Command::Diff,
+                        "subset" => Command::Subset,
+                        "disjoint" => Command::Disjoint,
+                        other => return Err(format!("unknown command \"{other}\"")),
+                    })
+                }
+                _ => parsed.files.push(flag.into()),
+            }
+        }
+
+        if parsed.help {
+            return Ok(parsed);
+        }
+
+        let expected = match parsed.command {
+            None => return Err("missing command".to_owned()),
+            Some(Command::Validate | Command::Merge) => None,
+            Some(Command::Normalise) => Some(1),
+            Some(Command::Diff | Command::Subset | Command::Disjoint) => Some(2),
+        };
+
+        match expected {
+            Some(count) if parsed.files.len() != count => {
+                return Err(format!(
+                    "expected {count} files, got {}",
+                    parsed.files.len()
+                ))
+            }
+            None if parsed.files.is_empty() => return Err("expected files".to_owned()),
+            _ => (),
+        }
+
+        let writes_list = matches!(parsed.command, Some(Command::Normalise | Command::Merge));
+
+        if parsed.output.is_some() && !writes_list {
+            return Err("--output only works with normalise and merge".to_owned());
+        }
+
+        Ok(parsed)
+    }
+}
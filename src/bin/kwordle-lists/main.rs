@@ -0,0 +1,219 @@
+//! Maintains word lists: validating, normalising, diffing and merging them.
+//!
+//! Run with `--help` for the options.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use kwordle::word::list::{answers::Answers, guessable::Guessable};
+use kwordle::word::words::{self, ParseLineError};
+use kwordle::Word;
+
+mod args;
+use args::{Args, Command};
+
+#[path = "../common/mod.rs"]
+mod common;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", args::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", args::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match common::dispatch_length!(args.length, run(&args)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A word-list file, with the words that parsed and the lines that didn't.
+struct ListFile<const N: usize> {
+    path: PathBuf,
+
+    /// Each word with the line it is on, starting from 1.
+    words: Vec<(usize, Word<N>)>,
+
+    errors: Vec<ParseLineError>,
+}
+
+impl<const N: usize> ListFile<N> {
+    /// Reads a list, keeping going past bad lines.
+    ///
+    /// If `tidy` is set, lines are trimmed and blank lines are skipped.
+    fn read(path: &Path, tidy: bool) -> Result<Self, String> {
+        let mut file = Self {
+            path: path.to_owned(),
+            words: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let contents = common::read(path)?;
+
+        let results: Box<dyn Iterator<Item = _>> = if tidy {
+            Box::new(words::parse_lines_lenient(&contents))
+        } else {
+            Box::new(words::parse_lines(&contents))
+        };
+
+        // blank lines have no result when tidying, but still count towards line numbers
+        let lines = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, text)| !tidy || !text.trim().is_empty())
+            .map(|(index, _)| index + 1);
+
+        for (line, result) in lines.zip(results) {
+            match result {
+                Ok(word) => file.words.push((line, word)),
+                Err(err) => file.errors.push(err),
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Prints every bad line to stderr, returning `false` if there were any.
+    fn report_errors(&self) -> bool {
+        for err in &self.errors {
+            eprintln!("{}: {err}", self.path.display());
+        }
+
+        self.errors.is_empty()
+    }
+
+    fn set(&self) -> BTreeSet<Word<N>> {
+        self.words.iter().map(|(_, word)| *word).collect()
+    }
+}
+
+fn run<const N: usize>(args: &Args) -> Result<bool, String> {
+    let command = args
+        .command
+        .expect("commands should be checked when parsing");
+
+    let tidy = matches!(command, Command::Normalise | Command::Merge);
+    let files = args
+        .files
+        .iter()
+        .map(|path| ListFile::<N>::read(path, tidy))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if command == Command::Validate {
+        // every file is checked, rather than stopping at the first bad one
+        let invalid = files.iter().filter(|file| !validate(file)).count();
+        return Ok(invalid == 0);
+    }
+
+    // every other command needs every file to parse
+    if files.iter().filter(|file| !file.report_errors()).count() > 0 {
+        return Ok(false);
+    }
+
+    match command {
+        Command::Validate => unreachable!(),
+        Command::Normalise | Command::Merge => {
+            let words = files.iter().flat_map(ListFile::set).collect();
+            write_list(args.output.as_deref(), &words)?;
+            Ok(true)
+        }
+        Command::Diff => {
+            let (old, new) = (files[0].set(), files[1].set());
+
+            for word in old.difference(&new) {
+                println!("-{word}");
+            }
+
+            for word in new.difference(&old) {
+                println!("+{word}");
+            }
+
+            Ok(old == new)
+        }
+        Command::Subset | Command::Disjoint => {
+            let (answers, allowed) = (&files[0], &files[1]);
+
+            let answers = Answers::try_from_iter(answers.set())
+                .ok_or_else(|| format!("{}: no words", answers.path.display()))?;
+            let allowed = Guessable::from_words(allowed.set())
+                .ok_or_else(|| format!("{}: no words", allowed.path.display()))?;
+
+            let (ok, reported): (_, BTreeSet<_>) = if command == Command::Subset {
+                let allowed_answers = allowed.answers_intersection(&answers);
+                let missing = answers
+                    .as_slice()
+                    .iter()
+                    .filter(|word| !allowed_answers.contains(word))
+                    .collect();
+
+                (allowed.includes_answers(&answers), missing)
+            } else {
+                let overlap = allowed.answers_intersection(&answers).into_iter().collect();
+                (allowed.excludes_answers(&answers), overlap)
+            };
+
+            for word in reported {
+                println!("{word}");
+            }
+
+            Ok(ok)
+        }
+    }
+}
+
+/// Reports bad lines and duplicates, returning `false` if there were bad lines.
+fn validate<const N: usize>(file: &ListFile<N>) -> bool {
+    let valid = file.report_errors();
+
+    let mut seen = BTreeMap::new();
+
+    for (line, word) in &file.words {
+        if let Some(first) = seen.insert(*word, *line) {
+            seen.insert(*word, first);
+            eprintln!(
+                "{}: line {line}: duplicate of line {first}: \"{word}\"",
+                file.path.display()
+            );
+        }
+    }
+
+    println!(
+        "{}: {} words, {} unique, {} bad lines",
+        file.path.display(),
+        file.words.len(),
+        seen.len(),
+        file.errors.len()
+    );
+
+    valid
+}
+
+fn write_list<const N: usize>(
+    output: Option<&Path>,
+    words: &BTreeSet<Word<N>>,
+) -> Result<(), String> {
+    let list: String = words.iter().map(|word| format!("{word}\n")).collect();
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, list).map_err(|err| format!("{}: {err}", path.display()))
+        }
+        None => {
+            print!("{list}");
+            Ok(())
+        }
+    }
+}
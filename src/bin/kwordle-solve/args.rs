@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use crate::common::parse_number;
+
+pub const USAGE: &str = "\
+usage: kwordle-solve [options]
+
+Helps solve a puzzle played elsewhere. Enter each guess and the feedback it got
+on stdin, one per line, like `crane .o..O`: `O` is correct, `o` is in the wrong
+place and `.` is not present. After each line, the remaining answers and the
+most informative next guesses are printed.
+
+options:
+    -l, --length <N>        letters per word (default 5; other lengths need --words)
+    -w, --words <FILE>      guessable words, one per line (default: the classic list)
+    -a, --answers <FILE>    possible answers, one per line (default: every guessable word)
+    -t, --top <N>           next guesses to suggest (default 5)
+    -c, --candidates <N>    remaining answers to list (default 10)
+        --only-candidates   only suggest guesses that could be the answer
+        --json              print one JSON object per line instead of text, listing
+                            as many remaining answers as --candidates
+    -h, --help              print this message";
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub length: usize,
+    pub words: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    pub top: usize,
+    pub candidates: usize,
+    pub only_candidates: bool,
+    pub json: bool,
+    pub help: bool,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            length: 5,
+            words: None,
+            answers: None,
+            top: 5,
+            candidates: 10,
+            only_candidates: false,
+            json: false,
+            help: false,
+        }
+    }
+}
+
+impl Args {
+    /// Parses arguments, not including the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_owned(), Some(value.to_owned()))
+                }
+                _ => (arg, None),
+            };
+
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{flag} needs a value"))
+            };
+
+            match flag.as_str() {
+                "-l" | "--length" => parsed.length = parse_number(&flag, &value()?)?,
+                "-w" | "--words" => parsed.words = Some(value()?.into()),
+                "-a" | "--answers" => parsed.answers = Some(value()?.into()),
+                "-t" | "--top" => parsed.top = parse_number(&flag, &value()?)?,
+                "-c" | "--candidates" => parsed.candidates = parse_number(&flag, &value()?)?,
+                "--only-candidates" => parsed.only_candidates = true,
+                "--json" => parsed.json = true,
+                "-h" | "--help" => parsed.help = true,
+                other => return Err(format!("unknown option \"{other}\"")),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
@@ -0,0 +1,192 @@
+//! Helps solve a puzzle played elsewhere, from guesses and their feedback.
+//!
+//! Run with `--help` for the options.
+
+use std::fmt::Write as _;
+use std::io::BufRead;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use kwordle::solver::{ScoredGuess, Solver};
+use kwordle::{Guess, Pattern, Word, WordsList};
+use serde::Serialize;
+
+mod args;
+use args::Args;
+
+#[path = "../common/mod.rs"]
+mod common;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", args::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", args::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match common::dispatch_length!(args.length, run(&args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The result of entering one line.
+#[derive(Serialize)]
+struct Step {
+    guess: String,
+    pattern: String,
+
+    /// The number of answers left, which may be more than are listed in `candidates`.
+    remaining: usize,
+    candidates: Vec<String>,
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Serialize)]
+struct Suggestion {
+    word: String,
+    entropy: f64,
+    candidate: bool,
+}
+
+fn run<const N: usize>(args: &Args) -> Result<(), String> {
+    let list = common::words_list::<N>(args.words.as_deref(), args.answers.as_deref())?;
+    let mut solver = Solver::new(&list);
+
+    for line in std::io::stdin().lock().lines() {
+        let line = line.map_err(|err| err.to_string())?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let guess = match parse_line(&list, line) {
+            Ok(guess) => guess,
+            Err(err) if args.json => {
+                println!("{}", serde_json::json!({ "error": err }));
+                continue;
+            }
+            Err(err) => {
+                println!("{err}");
+                continue;
+            }
+        };
+
+        solver.update(guess);
+
+        let suggestions = if solver.candidates().is_empty() {
+            Vec::new()
+        } else if args.only_candidates {
+            solver.ranked_candidates()
+        } else {
+            solver.ranked()
+        };
+
+        let step = Step::new(guess, solver.candidates(), suggestions, args);
+
+        if args.json {
+            let json = serde_json::to_string(&step).map_err(|err| err.to_string())?;
+            println!("{json}");
+        } else {
+            println!("{}", step.to_text());
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a line like `crane .o..O` into a guess.
+fn parse_line<const N: usize>(list: &WordsList<N>, line: &str) -> Result<Guess<N>, String> {
+    let Some((word, pattern)) = line.split_once(char::is_whitespace) else {
+        return Err(format!(
+            "expected a guess and its pattern, like `crane .o..O`, got \"{line}\""
+        ));
+    };
+
+    let word = Word::from_str(list, &word.to_lowercase())
+        .map_err(|err| format!("invalid guess \"{word}\": {err}"))?;
+    let pattern = Pattern::<N>::from_str(pattern.trim())
+        .map_err(|err| format!("invalid pattern \"{}\": {err}", pattern.trim()))?;
+
+    Ok(pattern.with_word(word))
+}
+
+impl Step {
+    fn new<const N: usize>(
+        guess: Guess<N>,
+        candidates: &[Word<N>],
+        suggestions: Vec<ScoredGuess<N>>,
+        args: &Args,
+    ) -> Self {
+        Self {
+            guess: guess.letters().map(char::from).collect(),
+            pattern: guess.pattern().to_string(),
+            remaining: candidates.len(),
+            candidates: candidates
+                .iter()
+                .take(args.candidates)
+                .map(Word::to_string)
+                .collect(),
+            suggestions: suggestions
+                .into_iter()
+                .take(args.top)
+                .map(|suggestion| Suggestion {
+                    word: suggestion.word().to_string(),
+                    // a guess that can't tell the candidates apart scores -0
+                    entropy: suggestion.entropy().abs(),
+                    candidate: suggestion.is_candidate(),
+                })
+                .collect(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        let count = self.remaining;
+        let plural = if count == 1 { "" } else { "s" };
+        writeln!(
+            text,
+            "{} {}: {count} answer{plural} left",
+            self.guess, self.pattern
+        )
+        .unwrap();
+
+        if !self.candidates.is_empty() {
+            let mut words = self.candidates.clone();
+
+            if count > words.len() {
+                words.push(format!("... and {} more", count - words.len()));
+            }
+
+            writeln!(text, "  {}", words.join(" ")).unwrap();
+        }
+
+        if !self.suggestions.is_empty() {
+            writeln!(text, "next guesses:").unwrap();
+
+            for suggestion in &self.suggestions {
+                let marker = if suggestion.candidate { " *" } else { "" };
+                writeln!(
+                    text,
+                    "  {} {:.3} bits{marker}",
+                    suggestion.word, suggestion.entropy
+                )
+                .unwrap();
+            }
+        }
+
+        text
+    }
+}
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+use kwordle::guess::Layout;
+use kwordle::render::Palette;
+
+use crate::common::parse_number;
+
+pub const USAGE: &str = "\
+usage: kwordle [options]
+
+Play wordle in the terminal. Guesses are read from stdin, one per line.
+
+options:
+    -l, --length <N>        letters per word (default 5; other lengths need --words)
+    -m, --max-guesses <N>   guesses allowed (default: length + 1)
+        --unlimited         allow any number of guesses
+        --hard              play in hard mode
+    -s, --seed <SEED>       choose the answer with a seed, to replay a game
+    -w, --words <FILE>      guessable words, one per line (default: the classic list)
+    -a, --answers <FILE>    possible answers, one per line (default: every guessable word)
+        --palette <NAME>    normal, colour-blind or none (default: normal, or none if NO_COLOR is set)
+        --layout <NAME>     qwerty, azerty or dvorak (default: qwerty)
+    -h, --help              print this message";
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub length: usize,
+    pub max_guesses: Option<Option<usize>>,
+    pub hard_mode: bool,
+    pub seed: Option<u64>,
+    pub words: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    pub palette: Palette,
+    pub layout: Layout,
+    pub help: bool,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        let palette = if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            Palette::NoColour
+        } else {
+            Palette::Normal
+        };
+
+        Self {
+            length: 5,
+            max_guesses: None,
+            hard_mode: false,
+            seed: None,
+            words: None,
+            answers: None,
+            palette,
+            layout: Layout::Qwerty,
+            help: false,
+        }
+    }
+}
+
+impl Args {
+    /// Parses arguments, not including the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_owned(), Some(value.to_owned()))
+                }
+                _ => (arg, None),
+            };
+
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{flag} needs a value"))
+            };
+
+            match flag.as_str() {
+                "-l" | "--length" => parsed.length = parse_number(&flag, &value()?)?,
+                "-m" | "--max-guesses" => {
+                    parsed.max_guesses = Some(Some(parse_number(&flag, &value()?)?))
+                }
+                "--unlimited" => parsed.max_guesses = Some(None),
+                "--hard" => parsed.hard_mode = true,
+                "-s" | "--seed" => parsed.seed = Some(parse_number(&flag, &value()?)?),
+                "-w" | "--words" => parsed.words = Some(value()?.into()),
+                "-a" | "--answers" => parsed.answers = Some(value()?.into()),
+                "--palette" => {
+                    parsed.palette = match value()?.as_str() {
+                        "normal" => Palette::Normal,
+                        "colour-blind" | "color-blind" => Palette::ColourBlind,
+                        "none" => Palette::NoColour,
+                        other => return Err(format!("unknown palette \"{other}\"")),
+                    }
+                }
+                "--layout" => {
+                    parsed.layout = match value()?.as_str() {
+                        "qwerty" => Layout::Qwerty,
+                        "azerty" => Layout::Azerty,
+                        "dvorak" => Layout::Dvorak,
+                        other => return Err(format!("unknown layout \"{other}\"")),
+                    }
+                }
+                "-h" | "--help" => parsed.help = true,
+                other => return Err(format!("unknown option \"{other}\"")),
+            }
+        }
+
+        if parsed.max_guesses == Some(Some(0)) {
+            return Err("--max-guesses must be at least 1".to_owned());
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns the guess limit, defaulting to one more than the word length.
+    pub fn max_guesses(&self) -> Option<usize> {
+        self.max_guesses.unwrap_or(Some(self.length + 1))
+    }
+}
@@ -0,0 +1,114 @@
+//! Plays a game of wordle in the terminal.
+//!
+//! Run with `--help` for the options.
+
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+use kwordle::game::GameStatus;
+use kwordle::guess::ShareOptions;
+use kwordle::render::Renderer;
+use kwordle::Game;
+
+mod args;
+use args::Args;
+
+#[path = "../common/mod.rs"]
+mod common;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", args::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", args::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match common::dispatch_length!(args.length, run(&args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run<const N: usize>(args: &Args) -> Result<(), String> {
+    let list = common::words_list::<N>(args.words.as_deref(), args.answers.as_deref())?;
+
+    let mut game = match args.seed {
+        Some(seed) => Game::from_seed(list, args.max_guesses(), seed),
+        None => Game::random(list, args.max_guesses()),
+    };
+    game.set_hard_mode(args.hard_mode);
+
+    let renderer = Renderer::new(args.palette);
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    if let Some(seed) = game.seed() {
+        println!("seed: {seed}");
+    }
+
+    loop {
+        print!("> ");
+        std::io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+
+        let line = line.map_err(|err| err.to_string())?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Err(err) = game.guess_str(&line.to_lowercase()) {
+            println!("{err}");
+            continue;
+        }
+
+        println!("\n{}\n", renderer.board(game.guesses()));
+        println!(
+            "{}\n",
+            renderer.keyboard(&game.guesses().keyboard(), args.layout)
+        );
+
+        if game.status().is_finished() {
+            break;
+        }
+    }
+
+    let answer = game
+        .answer()
+        .map(|answer| answer.to_string().to_uppercase())
+        .unwrap_or_default();
+
+    match game.status() {
+        GameStatus::Won { guesses } => {
+            let max = game
+                .guesses()
+                .max()
+                .map_or("∞".to_owned(), |max| max.to_string());
+
+            println!("Solved in {guesses}/{max}!\n");
+            println!("{}", game.share(&ShareOptions::default()));
+        }
+        GameStatus::Lost => {
+            println!("Out of guesses. The answer was {answer}.\n");
+            println!("{}", game.share(&ShareOptions::default()));
+        }
+        GameStatus::InProgress => println!("Gave up. The answer was {answer}."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+
+/// A day in the proleptic Gregorian calendar, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    days: i64,
+}
+
+impl Date {
+    /// Constructs a new `Date` from the number of days since 1970-01-01.
+    pub const fn from_days(days: i64) -> Self {
+        Self { days }
+    }
+
+    /// Constructs a new `Date` from a year, month (1-12) and day of the month (1-31).
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = year as i64 - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = month as i64;
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        Some(Self::from_days(era * 146097 + day_of_era - 719468))
+    }
+
+    /// Returns the current date in UTC.
+    pub fn today() -> Self {
+        Self::from_system_time(SystemTime::now())
+    }
+
+    /// Returns the date of a [`SystemTime`] in UTC.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let days = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => (since.as_secs() / SECONDS_PER_DAY) as i64,
+            Err(err) => -(err.duration().as_secs().div_ceil(SECONDS_PER_DAY) as i64),
+        };
+
+        Self::from_days(days)
+    }
+
+    /// The number of days since 1970-01-01.
+    pub const fn days(self) -> i64 {
+        self.days
+    }
+
+    /// Returns the year, month (1-12) and day of the month (1-31) of this date.
+    pub fn ymd(self) -> (i32, u32, u32) {
+        // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = self.days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+
+        (year, month, day)
+    }
+
+    pub const fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days + days)
+    }
+
+    /// The number of days from another date to this one.
+    pub const fn days_since(self, other: Self) -> i64 {
+        self.days - other.days
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.ymd();
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Date;
+
+    #[test]
+    fn known_dates() {
+        assert_eq!(Date::from_ymd(1970, 1, 1), Some(Date::from_days(0)));
+        assert_eq!(Date::from_ymd(2000, 3, 1), Some(Date::from_days(11017)));
+        assert_eq!(Date::from_ymd(2021, 6, 19), Some(Date::from_days(18797)));
+        assert_eq!(Date::from_ymd(1969, 12, 31), Some(Date::from_days(-1)));
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert!(Date::from_ymd(2024, 2, 29).is_some());
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+        assert_eq!(Date::from_ymd(1900, 2, 29), None);
+        assert_eq!(Date::from_ymd(2023, 13, 1), None);
+        assert_eq!(Date::from_ymd(2023, 4, 31), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for days in (-800_000..800_000).step_by(3) {
+            let date = Date::from_days(days);
+            let (year, month, day) = date.ymd();
+            assert_eq!(Date::from_ymd(year, month, day), Some(date));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Date::from_days(18797).to_string(), "2021-06-19");
+    }
+}
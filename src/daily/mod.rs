@@ -0,0 +1,206 @@
+//! Picks one answer per day, the same way for everyone, without a server.
+//!
+//! A [`Daily`] shuffles a set of [`Answers`] into a fixed order with a seed,
+//! and then numbers each day from an epoch [`Date`], starting from puzzle 0.
+//! Once every answer has been used, the order repeats.
+//!
+//! The shuffle does not depend on the `rand` crate, and the order for a given
+//! set of answers and seed will not change between versions of this crate.
+
+use std::collections::HashMap;
+
+use crate::rng::SplitMix64;
+use crate::{word::list::answers::Answers, Word};
+
+mod date;
+pub use date::Date;
+
+/// Assigns an answer to each day, starting from an epoch.
+#[derive(Debug, Clone)]
+pub struct Daily<const N: usize = 5> {
+    order: Box<[Word<N>]>,
+    positions: HashMap<Word<N>, u64>,
+    epoch: Date,
+}
+
+impl<const N: usize> Daily<N> {
+    /// The seed used by [`Daily::new`].
+    pub const DEFAULT_SEED: u64 = 0x006b_776f_7264_6c65;
+
+    /// Constructs a new `Daily` from some answers and the date of puzzle 0,
+    /// shuffled with [`Daily::DEFAULT_SEED`].
+    pub fn new(answers: &Answers<N>, epoch: Date) -> Self {
+        Self::with_seed(answers, epoch, Self::DEFAULT_SEED)
+    }
+
+    /// Constructs a new `Daily` from some answers and the date of puzzle 0,
+    /// shuffled with the given seed.
+    ///
+    /// The order of the answers in `answers` and any duplicates do not affect the result.
+    pub fn with_seed(answers: &Answers<N>, epoch: Date, seed: u64) -> Self {
+        let mut order = answers.as_slice().to_vec();
+        order.sort_unstable();
+        order.dedup();
+
+        shuffle(&mut order, seed);
+
+        let positions = order
+            .iter()
+            .enumerate()
+            .map(|(position, word)| (*word, position as u64))
+            .collect();
+
+        Self {
+            order: order.into_boxed_slice(),
+            positions,
+            epoch,
+        }
+    }
+
+    /// The date of puzzle 0.
+    pub fn epoch(&self) -> Date {
+        self.epoch
+    }
+
+    /// The number of puzzles before the order of answers repeats.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the answers in the order they will be used.
+    pub fn order(&self) -> &[Word<N>] {
+        &self.order
+    }
+
+    /// Returns the answer to a puzzle.
+    pub fn answer(&self, puzzle: u64) -> Word<N> {
+        self.order[(puzzle % self.order.len() as u64) as usize]
+    }
+
+    /// Returns the puzzle number for a date, or `None` if the date is before the epoch.
+    pub fn puzzle_on(&self, date: Date) -> Option<u64> {
+        u64::try_from(date.days_since(self.epoch)).ok()
+    }
+
+    /// Returns the date of a puzzle.
+    pub fn date_of(&self, puzzle: u64) -> Date {
+        self.epoch.add_days(puzzle as i64)
+    }
+
+    /// Returns the puzzle number and answer for a date,
+    /// or `None` if the date is before the epoch.
+    pub fn answer_on(&self, date: Date) -> Option<(u64, Word<N>)> {
+        let puzzle = self.puzzle_on(date)?;
+        Some((puzzle, self.answer(puzzle)))
+    }
+
+    /// Returns today's puzzle number and answer, or `None` if today is before the epoch.
+    pub fn today(&self) -> Option<(u64, Word<N>)> {
+        self.answer_on(Date::today())
+    }
+
+    /// Returns the first puzzle that a word is the answer to,
+    /// or `None` if it is not one of the answers.
+    ///
+    /// The word is also the answer to every puzzle [`Daily::len`] after this one.
+    pub fn puzzle_of(&self, word: Word<N>) -> Option<u64> {
+        self.positions.get(&word).copied()
+    }
+
+    /// Returns the latest puzzle on or before a date that a word was the answer to,
+    /// or `None` if it hasn't been the answer yet.
+    pub fn latest_puzzle_of(&self, word: Word<N>, date: Date) -> Option<u64> {
+        let first = self.puzzle_of(word)?;
+        let current = self.puzzle_on(date)?;
+        let len = self.order.len() as u64;
+
+        (current >= first).then(|| first + (current - first) / len * len)
+    }
+}
+
+fn shuffle<T>(slice: &mut [T], seed: u64) {
+    let mut rng = SplitMix64(seed);
+
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, rng.below(i + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{Daily, Date};
+    use crate::word::constants::word;
+    use crate::word::list::answers::Answers;
+
+    fn answers() -> Answers<5> {
+        Answers::from_str("amber\nember\number\nsonar\nhandy\nmummy\ntummy").unwrap()
+    }
+
+    fn epoch() -> Date {
+        Date::from_ymd(2024, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn stable_order() {
+        // if this changes, everyone's daily puzzles change with it
+        let daily = Daily::new(&answers(), epoch());
+
+        assert_eq!(
+            daily.order(),
+            ["umber", "mummy", "handy", "sonar", "tummy", "amber", "ember"].map(word)
+        );
+    }
+
+    #[test]
+    fn input_order_ignored() {
+        let reversed = Answers::try_from_iter(answers().into_iter().rev()).unwrap();
+
+        assert_eq!(
+            Daily::new(&answers(), epoch()).order(),
+            Daily::new(&reversed, epoch()).order()
+        );
+        assert_ne!(
+            Daily::new(&answers(), epoch()).order(),
+            Daily::with_seed(&answers(), epoch(), 1).order()
+        );
+    }
+
+    #[test]
+    fn dates() {
+        let daily = Daily::new(&answers(), epoch());
+
+        assert_eq!(daily.puzzle_on(epoch()), Some(0));
+        assert_eq!(
+            daily.puzzle_on(Date::from_ymd(2024, 2, 1).unwrap()),
+            Some(31)
+        );
+        assert_eq!(daily.puzzle_on(Date::from_ymd(2023, 12, 31).unwrap()), None);
+        assert_eq!(daily.date_of(31), Date::from_ymd(2024, 2, 1).unwrap());
+
+        assert_eq!(daily.answer_on(epoch()), Some((0, daily.order()[0])));
+        assert_eq!(daily.answer(7), daily.answer(0));
+    }
+
+    #[test]
+    fn inverse() {
+        let daily = Daily::new(&answers(), epoch());
+
+        for (puzzle, word) in daily.order().iter().enumerate() {
+            assert_eq!(daily.puzzle_of(*word), Some(puzzle as u64));
+        }
+
+        let word = daily.answer(3);
+        assert_eq!(daily.latest_puzzle_of(word, daily.date_of(2)), None);
+        assert_eq!(daily.latest_puzzle_of(word, daily.date_of(3)), Some(3));
+        assert_eq!(daily.latest_puzzle_of(word, daily.date_of(12)), Some(10));
+        assert_eq!(daily.latest_puzzle_of(word, daily.date_of(100)), Some(94));
+    }
+}
@@ -0,0 +1,73 @@
+//! Absurdle-style play, where the answer is not chosen up front.
+//!
+//! After each guess, the remaining answers are grouped by the [`Pattern`] the guess
+//! would produce against them, and the pattern that keeps the most answers is chosen.
+//! The game is only won once a single answer remains and it is guessed.
+
+use std::collections::BTreeMap;
+
+use crate::{Guess, Pattern, Word};
+
+/// Chooses the feedback for a guess that keeps the most candidates alive.
+///
+/// Ties are broken in favor of the pattern that reveals the least,
+/// i.e. the one with the lowest [`Pattern`] ordering.
+///
+/// Returns the chosen [`Guess`] and the candidates that are still consistent with it,
+/// or `None` if there are no candidates.
+pub fn respond<const N: usize>(
+    candidates: &[Word<N>],
+    word: Word<N>,
+) -> Option<(Guess<N>, Vec<Word<N>>)> {
+    let mut buckets: BTreeMap<Pattern<N>, Vec<Word<N>>> = BTreeMap::new();
+
+    for candidate in candidates {
+        buckets
+            .entry(candidate.guess(word).pattern())
+            .or_default()
+            .push(*candidate);
+    }
+
+    // `max_by_key` returns the last maximum, so iterate in reverse to prefer lower patterns
+    let (pattern, remaining) = buckets
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, bucket)| bucket.len())?;
+
+    Some((pattern.with_word(word), remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::respond;
+    use crate::word::constants::word;
+    use crate::Word;
+
+    fn words(s: &[&str]) -> Vec<Word<5>> {
+        s.iter().copied().map(word).collect()
+    }
+
+    #[test]
+    fn largest_bucket() {
+        let candidates = words(&["amber", "ember", "umber", "sonar"]);
+        let (guess, remaining) = respond(&candidates, word("amber")).unwrap();
+
+        assert_eq!(guess.pattern().to_string(), ".OOOO");
+        assert_eq!(remaining, words(&["ember", "umber"]));
+    }
+
+    #[test]
+    fn avoids_correct() {
+        let candidates = words(&["ember", "umber"]);
+        let (guess, remaining) = respond(&candidates, word("ember")).unwrap();
+
+        assert!(!guess.is_correct());
+        assert_eq!(remaining, words(&["umber"]));
+
+        let (guess, remaining) = respond(&remaining, word("umber")).unwrap();
+        assert!(guess.is_correct());
+        assert_eq!(remaining, words(&["umber"]));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::word::ParseWordError;
+
+use super::HardModeError;
+
+#[derive(Debug, Clone)]
+pub enum GameError {
+    InvalidWord(ParseWordError),
+    AlreadyWon { guesses: usize },
+    OutOfGuesses,
+    HardMode(HardModeError),
+}
+
+impl From<ParseWordError> for GameError {
+    fn from(value: ParseWordError) -> Self {
+        Self::InvalidWord(value)
+    }
+}
+
+impl From<HardModeError> for GameError {
+    fn from(value: HardModeError) -> Self {
+        Self::HardMode(value)
+    }
+}
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidWord(err) => Some(err),
+            Self::HardMode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidWord(err) => write!(f, "invalid word: {err}"),
+            Self::AlreadyWon { guesses } => {
+                write!(f, "game was already won in {guesses} guesses")
+            }
+            Self::OutOfGuesses => f.write_str("no guesses remaining"),
+            Self::HardMode(err) => write!(f, "hard mode: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: GameError)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Guesses, Knowledge, Letter, Word};
+
+/// Describes which revealed hint a guess failed to reuse in hard mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardModeError {
+    /// A letter revealed as [`LetterState::Correct`](crate::LetterState::Correct)
+    /// was not kept in its position.
+    MissingCorrect { letter: Letter, position: usize },
+
+    /// A letter revealed as [`LetterState::WrongPlace`](crate::LetterState::WrongPlace)
+    /// (or correct) was not used
+    /// as many times as previous guesses have shown it to appear.
+    MissingPresent {
+        letter: Letter,
+        required: usize,
+        found: usize,
+    },
+}
+
+impl std::error::Error for HardModeError {}
+
+impl std::fmt::Display for HardModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCorrect { letter, position } => {
+                let ch = letter.to_char().to_ascii_uppercase();
+                write!(f, "{} letter must be {ch}", ordinal(position + 1))
+            }
+            Self::MissingPresent {
+                letter, required, ..
+            } => {
+                let ch = letter.to_char().to_ascii_uppercase();
+
+                if *required == 1 {
+                    write!(f, "guess must contain {ch}")
+                } else {
+                    write!(f, "guess must contain {ch} {required} times")
+                }
+            }
+        }
+    }
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{n}{suffix}")
+}
+
+/// Checks that a `Word` reuses every hint revealed by previous guesses.
+///
+/// Correct letters must stay in place, and letters known to be present
+/// must appear at least as many times as the guesses have revealed them (see [`Knowledge`]).
+///
+/// # Errors
+/// Returns the first [`HardModeError`] found.
+pub fn check<const N: usize>(guesses: &Guesses<N>, word: Word<N>) -> Result<(), HardModeError> {
+    let knowledge = Knowledge::from_guesses(guesses);
+
+    for (position, letter) in word.letters.into_iter().enumerate() {
+        if let Some(fixed) = knowledge.fixed(position) {
+            if letter != fixed {
+                return Err(HardModeError::MissingCorrect {
+                    letter: fixed,
+                    position,
+                });
+            }
+        }
+    }
+
+    let found = word.letters_map();
+
+    for letter in crate::letter::alphabet_set() {
+        let required = knowledge.min_count(letter);
+        let found = found.count_letter(letter);
+
+        if found < required {
+            return Err(HardModeError::MissingPresent {
+                letter,
+                required,
+                found,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{check, HardModeError};
+    use crate::word::constants::word;
+    use crate::{Guesses, Letter};
+
+    fn guesses(answer: &str, words: &[&str]) -> Guesses<5> {
+        let mut guesses = Guesses::new(None);
+
+        for s in words {
+            guesses.push(word(answer).guess(word(s)));
+        }
+
+        guesses
+    }
+
+    #[test]
+    fn correct_must_stay() {
+        let guesses = guesses("amber", &["sonar"]);
+
+        assert_eq!(
+            check(&guesses, word("handy")),
+            Err(HardModeError::MissingCorrect {
+                letter: Letter::R,
+                position: 4
+            })
+        );
+        assert_eq!(check(&guesses, word("aster")), Ok(()));
+    }
+
+    #[test]
+    fn present_must_appear() {
+        let guesses = guesses("amber", &["handy"]);
+
+        assert_eq!(
+            check(&guesses, word("sonic")),
+            Err(HardModeError::MissingPresent {
+                letter: Letter::A,
+                required: 1,
+                found: 0
+            })
+        );
+        assert_eq!(check(&guesses, word("sonar")), Ok(()));
+    }
+
+    #[test]
+    fn duplicates() {
+        // "eerie" against "there": two e's are revealed, the third is not present
+        let guesses = guesses("there", &["eerie"]);
+
+        assert_eq!(
+            check(&guesses, word("rinse")),
+            Err(HardModeError::MissingPresent {
+                letter: Letter::E,
+                required: 2,
+                found: 1
+            })
+        );
+        assert_eq!(check(&guesses, word("three")), Ok(()));
+    }
+
+    #[test]
+    fn display() {
+        let err = HardModeError::MissingCorrect {
+            letter: Letter::A,
+            position: 0,
+        };
+        assert_eq!(err.to_string(), "1st letter must be A");
+
+        let err = HardModeError::MissingPresent {
+            letter: Letter::E,
+            required: 2,
+            found: 1,
+        };
+        assert_eq!(err.to_string(), "guess must contain E 2 times");
+    }
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: HardModeError)
+    }
+}
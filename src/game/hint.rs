@@ -0,0 +1,220 @@
+//! Hints that reveal part of the answer, or suggest a guess.
+//!
+//! Hints only ever reveal something that every possible answer agrees on,
+//! so they can be used in adversarial mode without giving away a single answer.
+
+use crate::{Knowledge, Letter, Word};
+
+/// The kinds of [`Hint`] that can be asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub enum HintKind {
+    /// Reveals the letter in a position that is not known yet.
+    Position,
+
+    /// Reveals a letter that is present, but not known to be yet.
+    Letter,
+
+    /// Suggests a word that is consistent with every guess so far.
+    Word,
+}
+
+impl std::fmt::Display for HintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Position => "position",
+            Self::Letter => "letter",
+            Self::Word => "word",
+        })
+    }
+}
+
+/// A hint given during a [`Game`](super::Game).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hint<const N: usize = 5> {
+    Position { letter: Letter, position: usize },
+    Letter(Letter),
+    Word(Word<N>),
+}
+
+impl<const N: usize> Hint<N> {
+    pub fn kind(&self) -> HintKind {
+        match self {
+            Self::Position { .. } => HintKind::Position,
+            Self::Letter(_) => HintKind::Letter,
+            Self::Word(_) => HintKind::Word,
+        }
+    }
+
+    /// Returns the letter this hint reveals, if it reveals one.
+    pub fn letter(&self) -> Option<Letter> {
+        match self {
+            Self::Position { letter, .. } | Self::Letter(letter) => Some(*letter),
+            Self::Word(_) => None,
+        }
+    }
+}
+
+/// A [`Hint`] in a game's history, with the number of guesses made before it was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UsedHint<const N: usize = 5> {
+    hint: Hint<N>,
+    turn: usize,
+}
+
+impl<const N: usize> UsedHint<N> {
+    pub(crate) fn new(hint: Hint<N>, turn: usize) -> Self {
+        Self { hint, turn }
+    }
+
+    pub fn hint(&self) -> Hint<N> {
+        self.hint
+    }
+
+    /// Returns the number of guesses that had been made when the hint was given.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+}
+
+/// Describes why a hint could not be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintError {
+    /// The game has already been won or lost.
+    Finished,
+
+    /// Every hint in the budget has been used.
+    NoHintsLeft { budget: usize },
+
+    /// There is nothing new to reveal with this kind of hint,
+    /// or the given hint is not one that could be given.
+    Unavailable { kind: HintKind },
+}
+
+impl std::error::Error for HintError {}
+
+impl std::fmt::Display for HintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Finished => f.write_str("game is already finished"),
+            Self::NoHintsLeft { budget } => write!(f, "all {budget} hints have been used"),
+            Self::Unavailable { kind } => write!(f, "no {kind} hint is available"),
+        }
+    }
+}
+
+/// Lists every hint of a kind that would reveal something new.
+///
+/// `possible` are the answers that could still be correct, `knowledge` is what the
+/// guesses have revealed, and `used` are the hints that have already been given.
+pub fn options<const N: usize>(
+    possible: &[Word<N>],
+    knowledge: &Knowledge<N>,
+    used: &[UsedHint<N>],
+    kind: HintKind,
+) -> Vec<Hint<N>> {
+    let given = |hint: &Hint<N>| used.iter().any(|used| used.hint == *hint);
+
+    let Some((first, rest)) = possible.split_first() else {
+        return Vec::new();
+    };
+
+    match kind {
+        HintKind::Position => (0..N)
+            .filter(|position| knowledge.fixed(*position).is_none())
+            .filter_map(|position| {
+                let letter = first.letters[position];
+
+                rest.iter()
+                    .all(|word| word.letters[position] == letter)
+                    .then_some(Hint::Position { letter, position })
+            })
+            .filter(|hint| !given(hint))
+            .collect(),
+        HintKind::Letter => {
+            let mut letters: Vec<_> = first
+                .letters
+                .iter()
+                .copied()
+                .filter(|letter| knowledge.min_count(*letter) == 0)
+                .filter(|letter| rest.iter().all(|word| word.letters.contains(letter)))
+                .filter(|letter| !used.iter().any(|used| used.hint.letter() == Some(*letter)))
+                .collect();
+
+            letters.sort();
+            letters.dedup();
+            letters.into_iter().map(Hint::Letter).collect()
+        }
+        HintKind::Word => possible
+            .iter()
+            .map(|word| Hint::Word(*word))
+            .filter(|hint| !given(hint))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{options, Hint, HintError, HintKind, UsedHint};
+    use crate::word::constants::word;
+    use crate::{Knowledge, Letter};
+
+    #[test]
+    fn agreed_by_every_answer() {
+        let possible = [word("mummy"), word("tummy")];
+        let knowledge = Knowledge::new();
+
+        assert_eq!(
+            options(&possible, &knowledge, &[], HintKind::Position),
+            [1, 2, 3, 4].map(|position| Hint::Position {
+                letter: word("mummy").letters[position],
+                position
+            })
+        );
+
+        assert_eq!(
+            options(&possible, &knowledge, &[], HintKind::Letter),
+            [
+                Hint::Letter(Letter::M),
+                Hint::Letter(Letter::U),
+                Hint::Letter(Letter::Y)
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_known() {
+        let answer = word("amber");
+        let knowledge = Knowledge::from(answer.guess(word("arbor")));
+        let used = [UsedHint::new(
+            Hint::Position {
+                letter: Letter::M,
+                position: 1,
+            },
+            1,
+        )];
+
+        assert_eq!(
+            options(&[answer], &knowledge, &used, HintKind::Position),
+            [Hint::Position {
+                letter: Letter::E,
+                position: 3
+            }]
+        );
+
+        assert_eq!(
+            options(&[answer], &knowledge, &used, HintKind::Letter),
+            [Hint::Letter(Letter::E)]
+        );
+    }
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: HintError)
+    }
+}
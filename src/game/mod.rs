@@ -0,0 +1,599 @@
+use crate::{Guess, Guesses, Word, WordsList};
+
+mod error;
+pub use error::GameError;
+
+mod status;
+pub use status::GameStatus;
+
+pub mod hard_mode;
+pub use hard_mode::HardModeError;
+
+pub mod adversarial;
+
+pub mod hint;
+pub use hint::{Hint, HintError, HintKind};
+
+pub mod multi;
+pub use multi::MultiGame;
+
+#[cfg(feature = "serde_derive")]
+mod serde;
+
+#[cfg(feature = "serde_derive")]
+pub use serde::GameDeserializer;
+
+#[derive(Debug, Clone, Copy)]
+enum Answer<const N: usize> {
+    Fixed(Word<N>),
+    Adversarial,
+}
+
+pub struct Game<const WORD_LEN: usize> {
+    answer: Answer<WORD_LEN>,
+    words_list: WordsList<WORD_LEN>,
+    guesses: Guesses<WORD_LEN>,
+    status: GameStatus,
+    /// The number of guesses made before hard mode was switched on, or `None` if it is off.
+    hard_mode: Option<usize>,
+    seed: Option<u64>,
+    hint_budget: Option<usize>,
+    hints: Vec<hint::UsedHint<WORD_LEN>>,
+}
+
+impl<const WORD_LEN: usize> Game<WORD_LEN> {
+    pub fn new(
+        answer: Word<WORD_LEN>,
+        words_list: WordsList<WORD_LEN>,
+        max_guesses: impl Into<Option<usize>>,
+    ) -> Self {
+        Self::with_answer(Answer::Fixed(answer), words_list, max_guesses)
+    }
+
+    /// Constructs a new `Game` in adversarial mode, where the answer is not chosen up front
+    /// and each guess is answered to keep as many answers possible as it can
+    /// (see [`adversarial`]).
+    pub fn adversarial(
+        words_list: WordsList<WORD_LEN>,
+        max_guesses: impl Into<Option<usize>>,
+    ) -> Self {
+        Self::with_answer(Answer::Adversarial, words_list, max_guesses)
+    }
+
+    fn with_answer(
+        answer: Answer<WORD_LEN>,
+        words_list: WordsList<WORD_LEN>,
+        max_guesses: impl Into<Option<usize>>,
+    ) -> Self {
+        let guesses = Guesses::new(max_guesses);
+
+        Self {
+            answer,
+            words_list,
+            status: GameStatus::from_guesses(&guesses),
+            guesses,
+            hard_mode: None,
+            seed: None,
+            hint_budget: None,
+            hints: Vec::new(),
+        }
+    }
+
+    /// Constructs a new `Game` with an answer chosen by a seeded random number generator.
+    ///
+    /// The same seed and [`WordsList`] will always produce the same answer,
+    /// so a game can be shared or replayed by its seed (see [`Game::seed`]).
+    /// The generator does not depend on the `rand` crate, so the answer for a seed
+    /// will not change between versions or platforms.
+    pub fn from_seed(
+        words_list: WordsList<WORD_LEN>,
+        max_guesses: impl Into<Option<usize>>,
+        seed: u64,
+    ) -> Self {
+        let answers = words_list.answers.as_slice();
+        let answer = answers[crate::rng::SplitMix64(seed).below(answers.len())];
+
+        let mut game = Self::new(answer, words_list, max_guesses);
+        game.seed = Some(seed);
+        game
+    }
+
+    /// Constructs a new `Game` with a random seed (see [`Game::from_seed`]).
+    #[cfg(feature = "rand_full")]
+    pub fn random(words_list: WordsList<WORD_LEN>, max_guesses: impl Into<Option<usize>>) -> Self {
+        Self::from_seed(words_list, max_guesses, rand::random())
+    }
+
+    /// Returns the seed this game's answer was chosen with, if it was constructed from one.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode.is_some()
+    }
+
+    /// Enables or disables hard mode, in which every guess must reuse
+    /// the hints revealed by previous guesses (see [`hard_mode::check`]).
+    ///
+    /// Only guesses made after hard mode is switched on are checked.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        if !hard_mode {
+            self.hard_mode = None;
+        } else if self.hard_mode.is_none() {
+            self.hard_mode = Some(self.guesses.count());
+        }
+    }
+
+    /// Returns the number of hints that can be used in this game, or `None` if there is no limit.
+    pub fn hint_budget(&self) -> Option<usize> {
+        self.hint_budget
+    }
+
+    pub fn set_hint_budget(&mut self, budget: impl Into<Option<usize>>) {
+        self.hint_budget = budget.into()
+    }
+
+    /// Returns the hints given so far, in order.
+    pub fn hints(&self) -> &[hint::UsedHint<WORD_LEN>] {
+        &self.hints
+    }
+
+    /// Returns the number of hints left in the budget, or `None` if there is no limit.
+    pub fn hints_remaining(&self) -> Option<usize> {
+        self.hint_budget
+            .map(|budget| budget.saturating_sub(self.hints.len()))
+    }
+
+    pub fn is_adversarial(&self) -> bool {
+        matches!(self.answer, Answer::Adversarial)
+    }
+
+    /// Returns the answer, if it is known.
+    ///
+    /// In adversarial mode, this is only known once a single answer remains.
+    pub fn answer(&self) -> Option<Word<WORD_LEN>> {
+        match self.answer {
+            Answer::Fixed(answer) => Some(answer),
+            Answer::Adversarial => match self.candidates().as_slice() {
+                [answer] => Some(*answer),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the answers that are still consistent with the guesses made so far.
+    pub fn candidates(&self) -> Vec<Word<WORD_LEN>> {
+        self.words_list.remaining_answers(&self.guesses)
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    pub fn guesses(&self) -> &Guesses<WORD_LEN> {
+        &self.guesses
+    }
+
+    pub fn words_list(&self) -> &WordsList<WORD_LEN> {
+        &self.words_list
+    }
+
+    /// Writes the share text for this game's guesses (see [`Guesses::share`]),
+    /// marking it as hard mode if hard mode is enabled, and with the number of hints used.
+    pub fn share(&self, options: &crate::guess::ShareOptions) -> String {
+        let options = crate::guess::ShareOptions {
+            hard_mode: options.hard_mode || self.is_hard_mode(),
+            hints: options.hints.max(self.hints.len()),
+            ..options.clone()
+        };
+
+        self.guesses.share(&options)
+    }
+
+    /// Checks whether the game will accept another guess.
+    ///
+    /// # Errors
+    /// Returns [`GameError::AlreadyWon`] if the answer has been found,
+    /// or [`GameError::OutOfGuesses`] if the guess limit has been reached.
+    pub fn check_in_progress(&self) -> Result<(), GameError> {
+        match self.status {
+            GameStatus::InProgress => Ok(()),
+            GameStatus::Won { guesses } => Err(GameError::AlreadyWon { guesses }),
+            GameStatus::Lost => Err(GameError::OutOfGuesses),
+        }
+    }
+
+    /// Guesses a `Word` against the answer, updating the status of the game.
+    ///
+    /// # Errors
+    /// Returns an error if the game is no longer in progress (see [`Game::check_in_progress`]),
+    /// or [`GameError::HardMode`] if hard mode is enabled and the word breaks its rules.
+    pub fn guess(&mut self, word: Word<WORD_LEN>) -> Result<Guess<WORD_LEN>, GameError> {
+        self.check_in_progress()?;
+
+        if self.is_hard_mode() {
+            hard_mode::check(&self.guesses, word)?;
+        }
+
+        let guess = match self.answer {
+            Answer::Fixed(answer) => answer.guess(word),
+            Answer::Adversarial => adversarial::respond(&self.candidates(), word)
+                .map(|(guess, _)| guess)
+                .expect("guesses should be consistent with at least one answer"),
+        };
+
+        self.guesses.push(guess);
+        self.status = GameStatus::from_guesses(&self.guesses);
+
+        Ok(guess)
+    }
+
+    /// Takes back the latest guess, returning it if there was one.
+    ///
+    /// The game's status is recalculated, so a finished game can be resumed.
+    /// Hints given after the guess are taken back too.
+    pub fn undo(&mut self) -> Option<Guess<WORD_LEN>> {
+        let guess = self.guesses.pop()?;
+        self.restore();
+
+        Some(guess)
+    }
+
+    /// Rewinds the game to the turn after its first `count` guesses,
+    /// taking back every later guess and returning how many were removed.
+    ///
+    /// The game's status is recalculated, so a finished game can be resumed.
+    /// Hints given after the last remaining guess are kept.
+    pub fn rewind(&mut self, count: usize) -> usize {
+        let removed = self.guesses.count().saturating_sub(count);
+
+        self.guesses.truncate(count);
+        self.restore();
+
+        removed
+    }
+
+    /// Recalculates the status and drops hints from turns that have been taken back.
+    fn restore(&mut self) {
+        let count = self.guesses.count();
+
+        self.status = GameStatus::from_guesses(&self.guesses);
+        self.hints.retain(|hint| hint.turn() <= count);
+
+        if let Some(since) = &mut self.hard_mode {
+            *since = (*since).min(count);
+        }
+    }
+
+    /// Lists every hint of a kind that would reveal something new (see [`hint::options`]).
+    pub fn hint_options(&self, kind: HintKind) -> Vec<Hint<WORD_LEN>> {
+        let possible = match self.answer {
+            Answer::Fixed(answer) if kind != HintKind::Word => vec![answer],
+            _ => self.candidates(),
+        };
+
+        hint::options(&possible, &self.guesses.knowledge(), &self.hints, kind)
+    }
+
+    /// Gives a specific hint, counting it against the budget and recording it in [`Game::hints`].
+    ///
+    /// # Errors
+    /// Returns [`HintError::Finished`] if the game is no longer in progress,
+    /// [`HintError::NoHintsLeft`] if the budget has been used up,
+    /// or [`HintError::Unavailable`] if the hint is not one of [`Game::hint_options`].
+    pub fn take_hint(&mut self, hint: Hint<WORD_LEN>) -> Result<(), HintError> {
+        self.check_hint_allowed()?;
+
+        if !self.hint_options(hint.kind()).contains(&hint) {
+            return Err(HintError::Unavailable { kind: hint.kind() });
+        }
+
+        self.hints
+            .push(hint::UsedHint::new(hint, self.guesses.count()));
+
+        Ok(())
+    }
+
+    /// Gives a random hint of a kind, using the given random number generator.
+    ///
+    /// # Errors
+    /// See [`Game::take_hint`].
+    #[cfg(feature = "rand")]
+    pub fn hint_with<R>(&mut self, kind: HintKind, rng: &mut R) -> Result<Hint<WORD_LEN>, HintError>
+    where
+        R: rand::Rng,
+    {
+        use rand::seq::SliceRandom;
+
+        self.check_hint_allowed()?;
+
+        let hint = *self
+            .hint_options(kind)
+            .choose(rng)
+            .ok_or(HintError::Unavailable { kind })?;
+
+        self.take_hint(hint)?;
+        Ok(hint)
+    }
+
+    /// Gives a random hint of a kind (see [`Game::hint_with`]).
+    ///
+    /// # Errors
+    /// See [`Game::take_hint`].
+    #[cfg(feature = "rand_full")]
+    pub fn hint(&mut self, kind: HintKind) -> Result<Hint<WORD_LEN>, HintError> {
+        self.hint_with(kind, &mut rand::thread_rng())
+    }
+
+    fn check_hint_allowed(&self) -> Result<(), HintError> {
+        if self.status.is_finished() {
+            return Err(HintError::Finished);
+        }
+
+        match self.hint_budget {
+            Some(budget) if self.hints.len() >= budget => Err(HintError::NoHintsLeft { budget }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses a string slice into a `Word` with this game's [`WordsList`],
+    /// and then guesses it against the answer.
+    ///
+    /// # Errors
+    /// Returns an error if the game is no longer in progress (see [`Game::check_in_progress`]),
+    /// [`GameError::InvalidWord`] if parsing the string into a `Word` fails,
+    /// or [`GameError::HardMode`] if hard mode is enabled and the word breaks its rules.
+    pub fn guess_str(&mut self, s: &str) -> Result<Guess<WORD_LEN>, GameError> {
+        self.check_in_progress()?;
+
+        let word = Word::from_str(&self.words_list, s)?;
+        self.guess(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{Game, GameError, GameStatus, HardModeError, Hint, HintError, HintKind};
+    use crate::word::constants::{self, word};
+    use crate::word::list::guessable::Guessable;
+    use crate::{Letter, Word, WordsList};
+
+    fn words_list() -> WordsList<5> {
+        constants::words_list("amber\nsonar\nmummy\ntummy\nhandy\narbor\naster")
+    }
+
+    fn game(max_guesses: impl Into<Option<usize>>) -> Game<5> {
+        let list = words_list();
+        let answer = Word::from_str(&list, "amber").unwrap();
+        Game::new(answer, list, max_guesses)
+    }
+
+    #[test]
+    fn won() {
+        let mut game = game(6);
+
+        assert_eq!(game.status(), GameStatus::InProgress);
+        game.guess_str("sonar").unwrap();
+        assert_eq!(game.status(), GameStatus::InProgress);
+        game.guess_str("amber").unwrap();
+        assert_eq!(game.status(), GameStatus::Won { guesses: 2 });
+
+        assert!(matches!(
+            game.guess_str("handy"),
+            Err(GameError::AlreadyWon { guesses: 2 })
+        ));
+        assert_eq!(game.guesses().count(), 2);
+    }
+
+    #[test]
+    fn lost() {
+        let mut game = game(2);
+
+        game.guess_str("sonar").unwrap();
+        game.guess_str("mummy").unwrap();
+        assert_eq!(game.status(), GameStatus::Lost);
+
+        assert!(matches!(
+            game.guess_str("amber"),
+            Err(GameError::OutOfGuesses)
+        ));
+        assert_eq!(game.guesses().count(), 2);
+    }
+
+    #[test]
+    fn unlimited() {
+        let mut game = game(None);
+
+        for _ in 0..10 {
+            game.guess_str("sonar").unwrap();
+        }
+
+        assert_eq!(game.status(), GameStatus::InProgress);
+    }
+
+    #[test]
+    fn invalid_word() {
+        let mut game = game(6);
+
+        assert!(matches!(
+            game.guess_str("zzzzz"),
+            Err(GameError::InvalidWord(_))
+        ));
+        assert_eq!(game.guesses().count(), 0);
+    }
+
+    #[test]
+    fn hard_mode() {
+        let mut game = game(6);
+        game.set_hard_mode(true);
+
+        game.guess_str("sonar").unwrap();
+
+        assert!(matches!(
+            game.guess_str("handy"),
+            Err(GameError::HardMode(HardModeError::MissingCorrect { .. }))
+        ));
+        assert_eq!(game.guesses().count(), 1);
+
+        game.guess_str("aster").unwrap();
+    }
+
+    #[test]
+    fn adversarial() {
+        let list: WordsList<5> =
+            WordsList::from_guessable(Guessable::from_str("amber\nember\number\nsonar").unwrap());
+        let mut game = Game::adversarial(list, None);
+
+        assert_eq!(game.answer(), None);
+
+        assert!(!game.guess_str("amber").unwrap().is_correct());
+        assert!(!game.guess_str("ember").unwrap().is_correct());
+        assert_eq!(
+            game.answer(),
+            Some(Word::from_str(game.words_list(), "umber").unwrap())
+        );
+
+        assert!(game.guess_str("umber").unwrap().is_correct());
+        assert_eq!(game.status(), GameStatus::Won { guesses: 3 });
+    }
+
+    #[test]
+    fn undo() {
+        let mut game = game(2);
+
+        game.guess_str("handy").unwrap();
+        game.guess_str("sonar").unwrap();
+        assert_eq!(game.status(), GameStatus::Lost);
+
+        assert_eq!(game.undo(), Some(word("amber").guess(word("sonar"))));
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert_eq!(
+            game.guesses().latest(),
+            Some(word("amber").guess(word("handy")))
+        );
+
+        game.guess_str("amber").unwrap();
+        assert_eq!(game.status(), GameStatus::Won { guesses: 2 });
+
+        game.undo();
+        game.undo();
+        assert_eq!(game.undo(), None);
+        assert_eq!(game.guesses().count(), 0);
+    }
+
+    #[test]
+    fn rewind() {
+        let mut game = game(None);
+
+        for s in ["handy", "sonar", "arbor", "amber"] {
+            game.guess_str(s).unwrap();
+        }
+
+        assert_eq!(game.rewind(5), 0);
+        assert_eq!(game.rewind(1), 3);
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert_eq!(game.guesses().count(), 1);
+        assert!(game.guesses().unused_letters().contains(&Letter::S));
+    }
+
+    #[test]
+    fn adversarial_undo() {
+        let mut game = Game::adversarial(words_list(), None);
+        let before = game.candidates();
+
+        game.guess_str("amber").unwrap();
+        game.undo();
+
+        assert_eq!(game.candidates(), before);
+    }
+
+    #[test]
+    fn hints() {
+        let mut game = game(6);
+        game.set_hint_budget(2);
+
+        game.guess_str("arbor").unwrap();
+        assert_eq!(
+            game.hint_options(HintKind::Letter),
+            [Hint::Letter(Letter::E), Hint::Letter(Letter::M)]
+        );
+
+        game.take_hint(Hint::Letter(Letter::E)).unwrap();
+        assert_eq!(
+            game.take_hint(Hint::Letter(Letter::E)),
+            Err(HintError::Unavailable {
+                kind: HintKind::Letter
+            })
+        );
+
+        game.guess_str("handy").unwrap();
+        game.take_hint(Hint::Word(word("amber"))).unwrap();
+        assert_eq!(game.hints_remaining(), Some(0));
+        assert_eq!(
+            game.take_hint(Hint::Letter(Letter::M)),
+            Err(HintError::NoHintsLeft { budget: 2 })
+        );
+
+        game.guess_str("amber").unwrap();
+        assert!(game
+            .share(&Default::default())
+            .starts_with("kwordle 3/6 💡2\n"));
+
+        game.rewind(1);
+        assert_eq!(game.hints().len(), 1);
+        assert_eq!(game.hints()[0].turn(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "rand_full")]
+    fn random_hint() {
+        use rand::SeedableRng;
+
+        let mut game = game(6);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+        for _ in 0..5 {
+            game.hint_with(HintKind::Position, &mut rng).unwrap();
+        }
+
+        assert_eq!(
+            game.hint_with(HintKind::Position, &mut rng),
+            Err(HintError::Unavailable {
+                kind: HintKind::Position
+            })
+        );
+    }
+
+    #[test]
+    fn seeded() {
+        let list: WordsList<5> = WordsList::from_guessable(
+            Guessable::from_str("amber\nember\number\nsonar\nhandy\nmummy\ntummy").unwrap(),
+        );
+
+        let game = Game::from_seed(list.clone(), 6, 12345);
+        assert_eq!(game.seed(), Some(12345));
+        assert_eq!(game.answer().unwrap(), "amber");
+
+        // if these change, every shared seed picks a different answer
+        for (seed, answer) in [(0, "umber"), (1, "mummy"), (u64::MAX, "umber")] {
+            assert_eq!(
+                Game::from_seed(list.clone(), 6, seed).answer().unwrap(),
+                answer
+            );
+        }
+
+        for _ in 0..10 {
+            assert_eq!(
+                Game::from_seed(list.clone(), 6, 12345).answer(),
+                game.answer()
+            );
+        }
+
+        assert_eq!(Game::new(game.answer().unwrap(), list, 6).seed(), None);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Guess, Word, WordsList};
+
+use super::{hard_mode, Game, GameError, GameStatus};
+
+/// Several [`Game`]s played at once, like Dordle, Quordle or Octordle.
+///
+/// Every guess is made on each board that hasn't been solved yet,
+/// and all boards share the same guess limit.
+pub struct MultiGame<const WORD_LEN: usize, const BOARDS: usize> {
+    boards: [Game<WORD_LEN>; BOARDS],
+    count: usize,
+    max: Option<usize>,
+}
+
+pub type Dordle = MultiGame<5, 2>;
+pub type Quordle = MultiGame<5, 4>;
+pub type Octordle = MultiGame<5, 8>;
+
+impl<const WORD_LEN: usize, const BOARDS: usize> MultiGame<WORD_LEN, BOARDS> {
+    /// # Panics
+    /// Panics if `BOARDS` is 0.
+    pub fn new(
+        answers: [Word<WORD_LEN>; BOARDS],
+        words_list: WordsList<WORD_LEN>,
+        max_guesses: impl Into<Option<usize>>,
+    ) -> Self {
+        assert!(BOARDS > 0, "a multi-board game needs at least one board");
+
+        let max = max_guesses.into();
+
+        Self {
+            boards: answers.map(|answer| Game::new(answer, words_list.clone(), max)),
+            count: 0,
+            max,
+        }
+    }
+
+    /// Constructs a new `MultiGame` with the usual guess limit of 5 more than the number of boards,
+    /// e.g. 7 for Dordle, 9 for Quordle and 13 for Octordle.
+    ///
+    /// # Panics
+    /// Panics if `BOARDS` is 0.
+    pub fn classic(answers: [Word<WORD_LEN>; BOARDS], words_list: WordsList<WORD_LEN>) -> Self {
+        Self::new(answers, words_list, BOARDS + 5)
+    }
+
+    pub fn boards(&self) -> &[Game<WORD_LEN>; BOARDS] {
+        &self.boards
+    }
+
+    pub fn board(&self, index: usize) -> Option<&Game<WORD_LEN>> {
+        self.boards.get(index)
+    }
+
+    /// Returns whether each board has been solved.
+    pub fn solved(&self) -> [bool; BOARDS] {
+        std::array::from_fn(|index| self.boards[index].status().is_won())
+    }
+
+    /// The number of guesses made so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    pub fn words_list(&self) -> &WordsList<WORD_LEN> {
+        self.boards[0].words_list()
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.boards.iter().any(Game::is_hard_mode)
+    }
+
+    /// Enables or disables hard mode on every board.
+    ///
+    /// Each guess must then reuse the hints revealed on every unsolved board.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        for board in &mut self.boards {
+            board.set_hard_mode(hard_mode)
+        }
+    }
+
+    /// Determines the status of the whole game:
+    /// it is won once every board is solved, and lost if any board is lost.
+    pub fn status(&self) -> GameStatus {
+        if self.boards.iter().any(|board| board.status().is_lost()) {
+            GameStatus::Lost
+        } else if self.boards.iter().all(|board| board.status().is_won()) {
+            GameStatus::Won {
+                guesses: self.count,
+            }
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    /// Checks whether the game will accept another guess.
+    ///
+    /// # Errors
+    /// See [`Game::check_in_progress`].
+    pub fn check_in_progress(&self) -> Result<(), GameError> {
+        match self.status() {
+            GameStatus::InProgress => Ok(()),
+            GameStatus::Won { guesses } => Err(GameError::AlreadyWon { guesses }),
+            GameStatus::Lost => Err(GameError::OutOfGuesses),
+        }
+    }
+
+    /// Guesses a `Word` on every unsolved board.
+    ///
+    /// Returns the [`Guess`] for each board, or `None` for boards that were already solved.
+    ///
+    /// # Errors
+    /// Returns an error if the game is no longer in progress (see [`MultiGame::check_in_progress`]),
+    /// or [`GameError::HardMode`] if hard mode is enabled and the word breaks its rules on any board.
+    /// No board is changed if an error is returned.
+    pub fn guess(
+        &mut self,
+        word: Word<WORD_LEN>,
+    ) -> Result<[Option<Guess<WORD_LEN>>; BOARDS], GameError> {
+        self.check_in_progress()?;
+
+        for board in self.unsolved() {
+            if board.is_hard_mode() {
+                hard_mode::check(board.guesses(), word)?;
+            }
+        }
+
+        let mut guesses = [None; BOARDS];
+
+        for (board, guess) in self.boards.iter_mut().zip(&mut guesses) {
+            if board.status().is_in_progress() {
+                *guess = Some(board.guess(word)?);
+            }
+        }
+
+        self.count += 1;
+
+        Ok(guesses)
+    }
+
+    /// Parses a string slice into a `Word` with this game's [`WordsList`],
+    /// and then guesses it on every unsolved board (see [`MultiGame::guess`]).
+    ///
+    /// # Errors
+    /// See [`MultiGame::guess`], and [`GameError::InvalidWord`] if parsing the string fails.
+    pub fn guess_str(&mut self, s: &str) -> Result<[Option<Guess<WORD_LEN>>; BOARDS], GameError> {
+        self.check_in_progress()?;
+
+        let word = Word::from_str(self.words_list(), s)?;
+        self.guess(word)
+    }
+
+    fn unsolved(&self) -> impl Iterator<Item = &Game<WORD_LEN>> {
+        self.boards
+            .iter()
+            .filter(|board| board.status().is_in_progress())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::Dordle;
+    use crate::game::{GameError, GameStatus};
+    use crate::word::list::guessable::Guessable;
+    use crate::{Word, WordsList};
+
+    fn dordle() -> Dordle {
+        let list: WordsList<5> = WordsList::from_guessable(
+            Guessable::from_str("amber\nember\number\nsonar\nhandy").unwrap(),
+        );
+        let answers = ["amber", "sonar"].map(|s| Word::from_str(&list, s).unwrap());
+
+        Dordle::classic(answers, list)
+    }
+
+    #[test]
+    fn boards_finish_independently() {
+        let mut game = dordle();
+
+        let guesses = game.guess_str("sonar").unwrap();
+        assert!(guesses[0].is_some_and(|guess| !guess.is_correct()));
+        assert!(guesses[1].is_some_and(|guess| guess.is_correct()));
+        assert_eq!(game.solved(), [false, true]);
+
+        let guesses = game.guess_str("amber").unwrap();
+        assert!(guesses[0].is_some_and(|guess| guess.is_correct()));
+        assert_eq!(guesses[1], None);
+
+        assert_eq!(game.status(), GameStatus::Won { guesses: 2 });
+        assert_eq!(game.board(1).unwrap().guesses().count(), 1);
+    }
+
+    #[test]
+    fn shared_budget() {
+        let mut game = dordle();
+        assert_eq!(game.max(), Some(7));
+
+        game.guess_str("amber").unwrap();
+        for _ in 1..7 {
+            game.guess_str("handy").unwrap();
+        }
+
+        assert_eq!(game.status(), GameStatus::Lost);
+        assert!(matches!(
+            game.guess_str("sonar"),
+            Err(GameError::OutOfGuesses)
+        ));
+    }
+
+    #[test]
+    fn hard_mode_is_atomic() {
+        let mut game = dordle();
+        game.set_hard_mode(true);
+
+        // reveals "mber" on the first board
+        game.guess_str("umber").unwrap();
+
+        assert!(matches!(
+            game.guess_str("handy"),
+            Err(GameError::HardMode(_))
+        ));
+        assert_eq!(game.count(), 1);
+        assert_eq!(game.board(0).unwrap().guesses().count(), 1);
+    }
+}
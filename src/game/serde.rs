@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Letter, Word, WordsList};
+
+use super::{hint::UsedHint, Answer, Game, Hint};
+
+/// The serialized form of a [`Game`], without its [`WordsList`].
+#[derive(Serialize, Deserialize)]
+struct GameData {
+    /// `None` in adversarial mode.
+    answer: Option<String>,
+    max_guesses: Option<usize>,
+    guesses: Vec<String>,
+    hard_mode: bool,
+    /// The number of guesses made before hard mode was switched on,
+    /// treated as 0 if it is missing.
+    #[serde(default)]
+    hard_mode_since: Option<usize>,
+    seed: Option<u64>,
+    #[serde(default)]
+    hint_budget: Option<usize>,
+    #[serde(default)]
+    hints: Vec<HintData>,
+}
+
+/// The serialized form of a [`UsedHint`].
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum HintData {
+    Position {
+        turn: usize,
+        letter: Letter,
+        position: usize,
+    },
+    Letter {
+        turn: usize,
+        letter: Letter,
+    },
+    Word {
+        turn: usize,
+        word: String,
+    },
+}
+
+impl HintData {
+    fn turn(&self) -> usize {
+        match self {
+            Self::Position { turn, .. } | Self::Letter { turn, .. } | Self::Word { turn, .. } => {
+                *turn
+            }
+        }
+    }
+}
+
+impl<const N: usize> From<&UsedHint<N>> for HintData {
+    fn from(value: &UsedHint<N>) -> Self {
+        let turn = value.turn();
+
+        match value.hint() {
+            Hint::Position { letter, position } => Self::Position {
+                turn,
+                letter,
+                position,
+            },
+            Hint::Letter(letter) => Self::Letter { turn, letter },
+            Hint::Word(word) => Self::Word {
+                turn,
+                word: word.to_string(),
+            },
+        }
+    }
+}
+
+impl<const N: usize> Serialize for Game<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let answer = match self.answer {
+            Answer::Fixed(answer) => Some(answer.to_string()),
+            Answer::Adversarial => None,
+        };
+
+        GameData {
+            answer,
+            max_guesses: self.guesses.max(),
+            guesses: self
+                .guesses
+                .iter()
+                .map(|guess| guess.letters().map(char::from).collect())
+                .collect(),
+            hard_mode: self.is_hard_mode(),
+            hard_mode_since: self.hard_mode,
+            seed: self.seed,
+            hint_budget: self.hint_budget,
+            hints: self.hints.iter().map(HintData::from).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a [`Game`] with a [`WordsList`].
+///
+/// The answer and every guess are checked against the list,
+/// and the guesses are replayed so that their results and the game's status
+/// are recalculated rather than trusted.
+#[derive(Clone, Debug)]
+pub struct GameDeserializer<const N: usize = 5> {
+    list: WordsList<N>,
+}
+
+impl<const N: usize> GameDeserializer<N> {
+    /// Creates a deserializer that checks games against `list`.
+    pub fn new(list: WordsList<N>) -> Self {
+        Self { list }
+    }
+}
+
+impl<'de, const N: usize> serde::de::DeserializeSeed<'de> for GameDeserializer<N> {
+    type Value = Game<N>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = GameData::deserialize(deserializer)?;
+
+        let mut game = match data.answer {
+            Some(answer) => {
+                let answer = Word::from_str(&self.list, &answer)
+                    .map_err(|err| serde::de::Error::custom(format!("invalid answer: {err}")))?;
+
+                Game::new(answer, self.list, data.max_guesses)
+            }
+            None => Game::adversarial(self.list, data.max_guesses),
+        };
+
+        game.seed = data.seed;
+
+        // hard mode and the budget may have changed during the game,
+        // so they are only applied from when they took effect
+        let hard_mode_since = data.hard_mode.then(|| data.hard_mode_since.unwrap_or(0));
+
+        if hard_mode_since.is_some_and(|since| since > data.guesses.len()) {
+            return Err(serde::de::Error::custom(
+                "hard mode was switched on after the last guess",
+            ));
+        }
+
+        let mut hints = data.hints.into_iter().peekable();
+
+        for turn in 0..=data.guesses.len() {
+            while let Some(hint) = hints.next_if(|hint| hint.turn() == turn) {
+                let hint = match hint {
+                    HintData::Position {
+                        letter, position, ..
+                    } => Hint::Position { letter, position },
+                    HintData::Letter { letter, .. } => Hint::Letter(letter),
+                    HintData::Word { word, .. } => {
+                        Hint::Word(Word::from_str(&game.words_list, &word).map_err(|err| {
+                            serde::de::Error::custom(format!("invalid hint \"{word}\": {err}"))
+                        })?)
+                    }
+                };
+
+                game.take_hint(hint)
+                    .map_err(|err| serde::de::Error::custom(format!("invalid hint: {err}")))?;
+            }
+
+            if hard_mode_since == Some(turn) {
+                game.hard_mode = Some(turn);
+            }
+
+            if let Some(guess) = data.guesses.get(turn) {
+                game.guess_str(guess).map_err(|err| {
+                    serde::de::Error::custom(format!("invalid guess \"{guess}\": {err}"))
+                })?;
+            }
+        }
+
+        if hints.next().is_some() {
+            return Err(serde::de::Error::custom("hints are out of order"));
+        }
+
+        game.hint_budget = data.hint_budget;
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde::de::DeserializeSeed;
+
+    use super::GameDeserializer;
+    use crate::game::{Game, GameStatus, Hint};
+    use crate::word::constants;
+    use crate::{Letter, Word, WordsList};
+
+    fn words_list() -> WordsList<5> {
+        constants::words_list("amber\nember\number\nsonar\nhandy")
+    }
+
+    fn deserialize(json: &str, list: WordsList<5>) -> Result<Game<5>, serde_json::Error> {
+        GameDeserializer::new(list).deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn round_trip() {
+        let list = words_list();
+        let mut game = Game::new(Word::from_str(&list, "amber").unwrap(), list.clone(), 6);
+        game.set_hard_mode(true);
+        game.guess_str("umber").unwrap();
+        game.guess_str("ember").unwrap();
+
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(
+            json,
+            r#"{"answer":"amber","max_guesses":6,"guesses":["umber","ember"],"hard_mode":true,"hard_mode_since":0,"seed":null,"hint_budget":null,"hints":[]}"#
+        );
+
+        let restored = deserialize(&json, list).unwrap();
+        assert_eq!(restored.answer(), game.answer());
+        assert!(restored.is_hard_mode());
+        assert_eq!(restored.status(), GameStatus::InProgress);
+        assert_eq!(restored.guesses().as_slice(), game.guesses().as_slice());
+    }
+
+    #[test]
+    fn hints() {
+        let list = words_list();
+        let mut game = Game::new(Word::from_str(&list, "amber").unwrap(), list.clone(), 6);
+        game.set_hint_budget(2);
+        game.take_hint(Hint::Letter(Letter::A)).unwrap();
+        game.guess_str("umber").unwrap();
+        game.take_hint(Hint::Position {
+            letter: Letter::A,
+            position: 0,
+        })
+        .unwrap();
+
+        let json = serde_json::to_string(&game).unwrap();
+        let restored = deserialize(&json, list).unwrap();
+
+        assert_eq!(restored.hints(), game.hints());
+        assert_eq!(restored.hints_remaining(), Some(0));
+
+        // the position was already revealed by the first guess
+        let json = r#"{"answer":"amber","max_guesses":6,"guesses":["umber"],"hard_mode":false,"seed":null,"hints":[{"kind":"position","turn":1,"letter":"m","position":1}]}"#;
+        assert!(deserialize(json, words_list()).is_err());
+    }
+
+    #[test]
+    fn settings_changed_during_game() {
+        let list = words_list();
+        let mut game = Game::new(Word::from_str(&list, "amber").unwrap(), list.clone(), 6);
+        game.set_hint_budget(1);
+        game.guess_str("sonar").unwrap();
+        game.take_hint(Hint::Letter(Letter::M)).unwrap();
+        game.guess_str("ember").unwrap();
+        game.set_hard_mode(true);
+        game.guess_str("amber").unwrap();
+        game.set_hint_budget(0);
+
+        let json = serde_json::to_string(&game).unwrap();
+        let restored = deserialize(&json, list).unwrap();
+
+        assert!(restored.is_hard_mode());
+        assert_eq!(restored.guesses().as_slice(), game.guesses().as_slice());
+        assert_eq!(restored.hints(), game.hints());
+        assert_eq!(restored.hint_budget(), Some(0));
+
+        // hard mode is still checked from when it was switched on
+        let json = r#"{"answer":"amber","max_guesses":6,"guesses":["ember","sonar","handy"],"hard_mode":true,"hard_mode_since":1,"seed":null}"#;
+        assert!(deserialize(json, words_list()).is_err());
+    }
+
+    #[test]
+    fn adversarial() {
+        let list = words_list();
+        let mut game = Game::adversarial(list.clone(), None);
+        game.guess_str("amber").unwrap();
+
+        let json = serde_json::to_string(&game).unwrap();
+        let restored = deserialize(&json, list).unwrap();
+
+        assert!(restored.is_adversarial());
+        assert_eq!(restored.candidates(), game.candidates());
+    }
+
+    #[test]
+    fn revalidates() {
+        let json = r#"{"answer":"amber","max_guesses":6,"guesses":["zzzzz"],"hard_mode":false,"seed":null}"#;
+        assert!(deserialize(json, words_list()).is_err());
+
+        let json =
+            r#"{"answer":"zzzzz","max_guesses":6,"guesses":[],"hard_mode":false,"seed":null}"#;
+        assert!(deserialize(json, words_list()).is_err());
+
+        // the second guess breaks hard mode
+        let json = r#"{"answer":"amber","max_guesses":6,"guesses":["ember","sonar"],"hard_mode":true,"seed":null}"#;
+        assert!(deserialize(json, words_list()).is_err());
+
+        // too many guesses
+        let json = r#"{"answer":"amber","max_guesses":1,"guesses":["ember","sonar"],"hard_mode":false,"seed":null}"#;
+        assert!(deserialize(json, words_list()).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::Guesses;
+
+/// Represents the progress of a [`Game`](super::Game).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameStatus {
+    /// The answer has not been found, and there are guesses left.
+    #[default]
+    InProgress,
+
+    /// The answer was found on the given guess.
+    Won { guesses: usize },
+
+    /// The guess limit was reached without finding the answer.
+    Lost,
+}
+
+impl GameStatus {
+    /// Determines the status of a game from the guesses made so far.
+    pub fn from_guesses<const N: usize>(guesses: &Guesses<N>) -> Self {
+        if guesses.latest_is_correct() {
+            Self::Won {
+                guesses: guesses.count(),
+            }
+        } else if guesses.max_reached() {
+            Self::Lost
+        } else {
+            Self::InProgress
+        }
+    }
+
+    pub fn is_in_progress(self) -> bool {
+        self == Self::InProgress
+    }
+
+    pub fn is_won(self) -> bool {
+        matches!(self, Self::Won { .. })
+    }
+
+    pub fn is_lost(self) -> bool {
+        self == Self::Lost
+    }
+
+    pub fn is_finished(self) -> bool {
+        !self.is_in_progress()
+    }
+}
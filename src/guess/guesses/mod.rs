@@ -1,5 +1,13 @@
 use crate::guess::Guess;
 
+#[cfg(feature = "serde_derive")]
+mod serde;
+
+#[cfg(feature = "serde_derive")]
+pub use serde::GuessesDeserializer;
+
+/// Serializable with the `serde_derive` feature,
+/// and deserializable with a [`GuessesDeserializer`].
 #[derive(Clone, Debug)]
 pub struct Guesses<const N: usize = 5> {
     max: Option<usize>,
@@ -56,6 +64,18 @@ impl<const N: usize> Guesses<N> {
         }
     }
 
+    /// Removes and returns the latest guess, if there is one.
+    pub fn pop(&mut self) -> Option<Guess<N>> {
+        self.vec.pop()
+    }
+
+    /// Keeps the first `count` guesses and removes the rest.
+    ///
+    /// Has no effect if there are `count` guesses or fewer.
+    pub fn truncate(&mut self, count: usize) {
+        self.vec.truncate(count)
+    }
+
     pub fn as_slice(&self) -> &[Guess<N>] {
         &self.vec
     }
@@ -64,6 +84,11 @@ impl<const N: usize> Guesses<N> {
         self.into_iter()
     }
 
+    /// Folds these guesses into a [`Knowledge`](crate::guess::Knowledge) of the answer.
+    pub fn knowledge(&self) -> crate::guess::Knowledge<N> {
+        crate::guess::Knowledge::from_guesses(self)
+    }
+
     pub fn unused_letters(&self) -> crate::letter::LetterSet {
         let mut set = crate::letter::alphabet_set();
 
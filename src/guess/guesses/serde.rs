@@ -0,0 +1,136 @@
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize};
+
+use crate::{Guess, Guesses, Letters, WordsList};
+
+/// The serialized form of [`Guesses`], for deserializing.
+#[derive(Deserialize)]
+#[serde(bound = "Guess<N>: for<'a> Deserialize<'a>")]
+struct GuessesData<const N: usize> {
+    max: Option<usize>,
+    guesses: Vec<Guess<N>>,
+}
+
+impl<const N: usize> Serialize for Guesses<N>
+where
+    Guess<N>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("Guesses", 2)?;
+        state.serialize_field("max", &self.max)?;
+        state.serialize_field("guesses", &self.vec)?;
+        state.end()
+    }
+}
+
+/// Deserializes [`Guesses`], checking that every guessed word is in a [`WordsList`].
+#[derive(Clone, Copy, Debug)]
+pub struct GuessesDeserializer<'list, const N: usize = 5> {
+    list: &'list WordsList<N>,
+}
+
+impl<'list, const N: usize> GuessesDeserializer<'list, N> {
+    /// Creates a deserializer that checks guesses against `list`.
+    pub fn new(list: &'list WordsList<N>) -> Self {
+        Self { list }
+    }
+}
+
+impl<'de, 'list, const N: usize> serde::de::DeserializeSeed<'de> for GuessesDeserializer<'list, N>
+where
+    Guess<N>: for<'a> Deserialize<'a>,
+{
+    type Value = Guesses<N>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = GuessesData::<N>::deserialize(deserializer)?;
+        let guesses = Guesses {
+            max: data.max,
+            vec: data.guesses,
+        };
+
+        if guesses.max().is_some_and(|max| guesses.count() > max) {
+            return Err(serde::de::Error::custom(format!(
+                "{} guesses is more than the maximum of {}",
+                guesses.count(),
+                guesses.max().unwrap_or_default()
+            )));
+        }
+
+        if let Some(index) = guesses.iter().position(|guess| guess.is_correct()) {
+            if index + 1 < guesses.count() {
+                return Err(serde::de::Error::custom(
+                    "guesses continue after a correct guess",
+                ));
+            }
+        }
+
+        for guess in &guesses {
+            let letters = Letters::new(guess.letters.map(|(letter, _)| letter));
+
+            if !self.list.guessable.contains_letters(letters) {
+                let word: String = guess.letters().map(char::from).collect();
+                return Err(serde::de::Error::custom(format!(
+                    "word \"{word}\" not found in list"
+                )));
+            }
+        }
+
+        Ok(guesses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+    use serde::de::DeserializeSeed;
+
+    use super::GuessesDeserializer;
+    use crate::word::list::guessable::Guessable;
+    use crate::{Guesses, Word, WordsList};
+
+    #[test]
+    fn round_trip() {
+        let list: WordsList<5> =
+            WordsList::from_guessable(Guessable::from_str("amber\nsonar").unwrap());
+        let answer = Word::from_str(&list, "amber").unwrap();
+
+        let mut guesses = Guesses::new(6);
+        guesses.push(answer.guess(Word::from_str(&list, "sonar").unwrap()));
+
+        let json = serde_json::to_string(&guesses).unwrap();
+        assert!(json.starts_with(r#"{"max":6,"guesses":["#));
+
+        let deserializer = GuessesDeserializer::new(&list);
+        let restored = deserializer
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+
+        assert_eq!(restored.as_slice(), guesses.as_slice());
+        assert_eq!(restored.max(), Some(6));
+
+        let mut after_win = Guesses::new(6);
+        after_win.push(answer.guess(answer));
+        after_win.push(answer.guess(Word::from_str(&list, "sonar").unwrap()));
+
+        let json = serde_json::to_string(&after_win).unwrap();
+        assert!(GuessesDeserializer::new(&list)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .is_err());
+
+        let json = serde_json::to_string(&guesses).unwrap();
+        let other: WordsList<5> = WordsList::from_guessable(Guessable::from_str("amber").unwrap());
+        let deserializer = GuessesDeserializer::new(&other);
+        assert!(deserializer
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .is_err());
+    }
+}
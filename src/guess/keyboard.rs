@@ -0,0 +1,196 @@
+use crate::{Guess, Guesses, Letter, LetterState};
+
+/// The best-known [`LetterState`] of every letter, for showing an on-screen keyboard.
+///
+/// A letter's state only ever improves: [`LetterState::Correct`] beats
+/// [`LetterState::WrongPlace`], which beats [`LetterState::NotPresent`].
+/// Letters that have not been guessed have no state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyboardState {
+    states: [Option<LetterState>; Letter::COUNT],
+}
+
+impl KeyboardState {
+    /// Constructs a new `KeyboardState` where every letter is untried.
+    pub fn new() -> Self {
+        Self {
+            states: [None; Letter::COUNT],
+        }
+    }
+
+    pub fn from_guesses<const N: usize>(guesses: &Guesses<N>) -> Self {
+        let mut keyboard = Self::new();
+
+        for guess in guesses {
+            keyboard.update(*guess);
+        }
+
+        keyboard
+    }
+
+    /// Adds the states revealed by another guess.
+    pub fn update<const N: usize>(&mut self, guess: Guess<N>) {
+        for (letter, state) in guess {
+            let best = &mut self.states[letter.index()];
+            *best = (*best).max(Some(state));
+        }
+    }
+
+    /// Returns the best-known state of a letter, or `None` if it is untried.
+    pub fn get(&self, letter: Letter) -> Option<LetterState> {
+        self.states[letter.index()]
+    }
+
+    pub fn is_untried(&self, letter: Letter) -> bool {
+        self.get(letter).is_none()
+    }
+
+    /// Iterates over every letter in alphabetical order with its state.
+    pub fn iter(&self) -> impl Iterator<Item = (Letter, Option<LetterState>)> + '_ {
+        crate::letter::alphabet_set()
+            .into_iter()
+            .map(|letter| (letter, self.get(letter)))
+    }
+
+    /// Returns the letters of each row of a keyboard layout with their states, from top to bottom.
+    pub fn rows(
+        &self,
+        layout: Layout,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Letter, Option<LetterState>)> + '_> + '_ {
+        layout
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|letter| (*letter, self.get(*letter))))
+    }
+}
+
+impl Default for KeyboardState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<&Guesses<N>> for KeyboardState {
+    fn from(value: &Guesses<N>) -> Self {
+        Self::from_guesses(value)
+    }
+}
+
+impl<const N: usize> Extend<Guess<N>> for KeyboardState {
+    fn extend<T: IntoIterator<Item = Guess<N>>>(&mut self, iter: T) {
+        for guess in iter {
+            self.update(guess);
+        }
+    }
+}
+
+/// The arrangement of letters on a physical keyboard, for rendering a [`KeyboardState`].
+///
+/// Only letter keys are included.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub enum Layout {
+    #[default]
+    Qwerty,
+    Azerty,
+    Dvorak,
+}
+
+impl Layout {
+    /// Returns the letters in each row, from top to bottom and left to right.
+    pub fn rows(self) -> &'static [&'static [Letter]] {
+        use Letter::*;
+
+        match self {
+            Self::Qwerty => &[
+                &[Q, W, E, R, T, Y, U, I, O, P],
+                &[A, S, D, F, G, H, J, K, L],
+                &[Z, X, C, V, B, N, M],
+            ],
+            Self::Azerty => &[
+                &[A, Z, E, R, T, Y, U, I, O, P],
+                &[Q, S, D, F, G, H, J, K, L, M],
+                &[W, X, C, V, B, N],
+            ],
+            Self::Dvorak => &[
+                &[P, Y, F, G, C, R, L],
+                &[A, O, E, U, I, D, H, T, N, S],
+                &[Q, J, K, X, B, M, W, V, Z],
+            ],
+        }
+    }
+}
+
+impl<const N: usize> Guesses<N> {
+    /// Computes the [`KeyboardState`] after these guesses.
+    pub fn keyboard(&self) -> KeyboardState {
+        KeyboardState::from_guesses(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{KeyboardState, Layout};
+    use crate::word::constants::word;
+    use crate::{Guesses, Letter, LetterState};
+
+    #[test]
+    fn best_state() {
+        let mut guesses = Guesses::new(6);
+        guesses.push(word("amber").guess(word("arbor")));
+        guesses.push(word("amber").guess(word("handy")));
+
+        let keyboard = guesses.keyboard();
+
+        assert_eq!(keyboard.get(Letter::A), Some(LetterState::Correct));
+        assert_eq!(keyboard.get(Letter::B), Some(LetterState::Correct));
+        assert_eq!(keyboard.get(Letter::O), Some(LetterState::NotPresent));
+        assert_eq!(keyboard.get(Letter::M), None);
+        assert!(keyboard.is_untried(Letter::Z));
+
+        let mut incremental = KeyboardState::new();
+        incremental.extend(guesses.iter().copied());
+        assert_eq!(incremental, keyboard);
+    }
+
+    #[test]
+    fn layouts_cover_alphabet() {
+        for layout in [Layout::Qwerty, Layout::Azerty, Layout::Dvorak] {
+            let mut letters: Vec<_> = layout.rows().concat();
+            letters.sort();
+
+            assert_eq!(
+                letters,
+                crate::letter::alphabet_set()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn rows() {
+        let mut guesses = Guesses::new(6);
+        guesses.push(word("amber").guess(word("queen")));
+
+        let keyboard = guesses.keyboard();
+        let top: Vec<_> = keyboard
+            .rows(Layout::Qwerty)
+            .next()
+            .unwrap()
+            .take(3)
+            .collect();
+
+        assert_eq!(
+            top,
+            [
+                (Letter::Q, Some(LetterState::NotPresent)),
+                (Letter::W, None),
+                (Letter::E, Some(LetterState::Correct)),
+            ]
+        );
+    }
+}
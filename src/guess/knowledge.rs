@@ -0,0 +1,189 @@
+use crate::{letter::LetterSet, word::LettersMap, Guess, Guesses, Letter, LetterState, Word};
+
+/// Everything that a set of guesses has revealed about the answer.
+///
+/// This folds the [`LetterState`]s of each [`Guess`] into per-position and
+/// per-letter constraints, taking into account how repeated letters are scored
+/// by [`Word::guess`].
+#[derive(Debug, Clone)]
+pub struct Knowledge<const N: usize = 5> {
+    fixed: [Option<Letter>; N],
+    forbidden: [LetterSet; N],
+    min_counts: LettersMap,
+    exact: LetterSet,
+}
+
+impl<const N: usize> Knowledge<N> {
+    /// Constructs a new `Knowledge` with no constraints.
+    pub fn new() -> Self {
+        Self {
+            fixed: [None; N],
+            forbidden: [LetterSet::new(); N],
+            min_counts: LettersMap::new(),
+            exact: LetterSet::new(),
+        }
+    }
+
+    pub fn from_guesses(guesses: &Guesses<N>) -> Self {
+        let mut knowledge = Self::new();
+
+        for guess in guesses {
+            knowledge.update(*guess);
+        }
+
+        knowledge
+    }
+
+    /// Adds the information revealed by another guess.
+    pub fn update(&mut self, guess: Guess<N>) {
+        let mut found = LettersMap::new();
+        let mut missed = LetterSet::new();
+
+        for (position, (letter, state)) in guess.into_iter().enumerate() {
+            match state {
+                LetterState::Correct => {
+                    self.fixed[position] = Some(letter);
+                    found.increment(letter);
+                }
+                LetterState::WrongPlace => {
+                    self.forbidden[position].insert(letter);
+                    found.increment(letter);
+                }
+                LetterState::NotPresent => {
+                    self.forbidden[position].insert(letter);
+                    missed.insert(letter);
+                }
+            }
+        }
+
+        for letter in guess.letters() {
+            let count = found.count_letter(letter);
+
+            if count > self.min_count(letter) {
+                self.min_counts.set_count(letter, count);
+            }
+
+            // a letter scored as not present means every copy of it has been accounted for
+            if missed.contains(&letter) {
+                self.exact.insert(letter);
+            }
+        }
+    }
+
+    /// Returns the letter known to be at a position, if any.
+    pub fn fixed(&self, position: usize) -> Option<Letter> {
+        self.fixed.get(position).copied().flatten()
+    }
+
+    /// Returns the set of letters known not to be at a position.
+    pub fn forbidden(&self, position: usize) -> Option<&LetterSet> {
+        self.forbidden.get(position)
+    }
+
+    /// Returns the minimum number of times a letter must appear in the answer.
+    pub fn min_count(&self, letter: Letter) -> usize {
+        self.min_counts.count_letter(letter)
+    }
+
+    /// Returns the exact number of times a letter appears in the answer, if it is known.
+    pub fn exact_count(&self, letter: Letter) -> Option<usize> {
+        self.exact.contains(&letter).then(|| self.min_count(letter))
+    }
+
+    /// Checks whether a `Word` could still be the answer.
+    pub fn is_consistent(&self, word: &Word<N>) -> bool {
+        for (position, letter) in word.letters.into_iter().enumerate() {
+            if self.fixed[position].is_some_and(|fixed| fixed != letter)
+                || self.forbidden[position].contains(&letter)
+            {
+                return false;
+            }
+        }
+
+        let counts = word.letters_map();
+
+        for (letter, min) in self.min_counts.iter() {
+            if counts.count_letter(letter) < min {
+                return false;
+            }
+        }
+
+        for letter in &self.exact {
+            if counts.count_letter(*letter) != self.min_count(*letter) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<const N: usize> Default for Knowledge<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<&Guesses<N>> for Knowledge<N> {
+    fn from(value: &Guesses<N>) -> Self {
+        Self::from_guesses(value)
+    }
+}
+
+impl<const N: usize> From<Guess<N>> for Knowledge<N> {
+    fn from(value: Guess<N>) -> Self {
+        let mut knowledge = Self::new();
+        knowledge.update(value);
+        knowledge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Knowledge;
+    use crate::word::constants::word;
+    use crate::Letter;
+
+    const WORDS: &[&str] = &[
+        "amber", "arbor", "mummy", "tummy", "there", "three", "eerie", "rinse", "ether", "sonar",
+        "handy", "level", "speed", "erase", "geese", "tepee", "addra", "opals",
+    ];
+
+    #[test]
+    fn duplicates() {
+        let knowledge = Knowledge::from(word("there").guess(word("eerie")));
+
+        assert_eq!(knowledge.fixed(4), Some(Letter::E));
+        assert_eq!(knowledge.exact_count(Letter::E), Some(2));
+        assert_eq!(knowledge.min_count(Letter::R), 1);
+        assert_eq!(knowledge.exact_count(Letter::R), None);
+        assert_eq!(knowledge.exact_count(Letter::I), Some(0));
+
+        assert!(knowledge.is_consistent(&word("there")));
+        assert!(!knowledge.is_consistent(&word("three")));
+        assert!(!knowledge.is_consistent(&word("rinse")));
+        assert!(!knowledge.is_consistent(&word("ether")));
+    }
+
+    #[test]
+    fn matches_guess() {
+        // a word is consistent with a single guess exactly when
+        // it would have produced the same feedback as the answer
+        for answer in WORDS.iter().copied().map(word) {
+            for guessed in WORDS.iter().copied().map(word) {
+                let guess = answer.guess(guessed);
+                let knowledge = Knowledge::from(guess);
+
+                for candidate in WORDS.iter().copied().map(word) {
+                    assert_eq!(
+                        knowledge.is_consistent(&candidate),
+                        candidate.guess(guessed) == guess,
+                        "answer {answer}, guess {guessed}, candidate {candidate}"
+                    );
+                }
+            }
+        }
+    }
+}
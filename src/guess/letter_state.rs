@@ -5,7 +5,7 @@ use crate::letter::Letter;
 /// In classic wordle, this is displayed by the color of each letter tile.
 /// In a more general sense, this is the primary means by which information
 /// about the puzzle's answer is communicated to the player.
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 #[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
 pub enum LetterState {
     #[default]
@@ -22,4 +22,37 @@ impl LetterState {
     pub fn is_correct(self) -> bool {
         self == Self::Correct
     }
+
+    /// Converts this state to the character used to write [`Pattern`](super::Pattern)s:
+    /// `'O'` for correct, `'o'` for wrong place and `'.'` for not present.
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Correct => 'O',
+            Self::WrongPlace => 'o',
+            Self::NotPresent => '.',
+        }
+    }
+
+    /// Parses a character written by [`LetterState::to_char`].
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'O' => Some(Self::Correct),
+            'o' => Some(Self::WrongPlace),
+            '.' => Some(Self::NotPresent),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_digit(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            0 => Some(Self::NotPresent),
+            1 => Some(Self::WrongPlace),
+            2 => Some(Self::Correct),
+            _ => None,
+        }
+    }
 }
@@ -9,6 +9,18 @@ use crate::Array;
 mod letter_state;
 pub use letter_state::LetterState;
 
+pub mod knowledge;
+pub use knowledge::Knowledge;
+
+pub mod pattern;
+pub use pattern::Pattern;
+
+pub mod share;
+pub use share::ShareOptions;
+
+pub mod keyboard;
+pub use keyboard::{KeyboardState, Layout};
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -28,6 +40,22 @@ impl<const N: usize> Guess<N> {
         }
     }
 
+    /// Pairs each letter with the state at the same position.
+    pub const fn from_states(letters: Array<Letter, N>, states: [LetterState; N]) -> Self {
+        let letters = letters.as_array();
+        let mut pairs = [(Letter::A, LetterState::NotPresent); N];
+        let mut i = 0;
+
+        while i < N {
+            pairs[i] = (letters[i], states[i]);
+            i += 1;
+        }
+
+        Self {
+            letters: Array::new(pairs),
+        }
+    }
+
     pub fn letters(&self) -> std::array::IntoIter<Letter, N> {
         self.letters.map(|(letter, _)| letter).into_iter()
     }
@@ -40,6 +68,11 @@ impl<const N: usize> Guess<N> {
         self.states().all(LetterState::is_correct)
     }
 
+    /// Returns the [`Pattern`] of letter states in this guess.
+    pub fn pattern(self) -> Pattern<N> {
+        Pattern::from(self)
+    }
+
     pub fn get(self, index: usize) -> Option<(Letter, LetterState)> {
         self.letters.get(index).copied()
     }
@@ -165,80 +198,17 @@ impl<'g, const N: usize> IntoIterator for &'g mut Guess<N> {
 
 #[cfg(test)]
 mod tests {
-    use super::LetterState;
-
-    mod fmt {
-        pub trait Test {
-            fn fmt(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result;
-
-            fn to_string(&self) -> String {
-                let mut s = String::new();
-                self.fmt(&mut s).unwrap();
-                s
-            }
-
-            fn from_str(s: &str) -> Option<Self>
-            where
-                Self: Sized;
-        }
-    }
-
-    impl fmt::Test for LetterState {
-        fn fmt(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-            f.write_char(match self {
-                Self::Correct => 'O',
-                Self::WrongPlace => 'o',
-                Self::NotPresent => '.',
-            })
-        }
-
-        fn from_str(s: &str) -> Option<Self>
-        where
-            Self: Sized,
-        {
-            match s {
-                "O" => Some(Self::Correct),
-                "o" => Some(Self::WrongPlace),
-                "." => Some(Self::NotPresent),
-                _ => None,
-            }
-        }
-    }
-
-    impl fmt::Test for Vec<LetterState> {
-        fn fmt(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-            for state in self {
-                fmt::Test::fmt(state, f)?;
-            }
-
-            Ok(())
-        }
-
-        fn from_str(s: &str) -> Option<Self>
-        where
-            Self: Sized,
-        {
-            Some(
-                s.chars()
-                    .filter_map(|ch| LetterState::from_str(&ch.to_string()))
-                    .collect(),
-            )
-        }
-    }
-
     macro_rules! string_match {
         ($($word:ident, $guess:ident => $result:literal;)+) => {
             paste::paste! {
                 $(
                     #[test]
                     fn [<$word _ $guess>]() {
-                        use fmt::Test;
-
                         unsafe {
                             let word: crate::Word<5> = crate::Word::from_str_unchecked(&stringify!($word)).unwrap();
                             let guess = word.guess(crate::Word::from_str_unchecked(&stringify!($guess)).unwrap());
                             pretty_assertions::assert_eq!(
-                                guess.states().collect::<Vec<_>>().to_string(), $result
+                                guess.pattern().to_string(), $result
                             )
                         }
                     }
@@ -0,0 +1,44 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone)]
+pub enum ParsePatternError {
+    InvalidChar(char),
+    WrongLength(crate::array::LengthError),
+}
+
+impl Error for ParsePatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::WrongLength(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ParsePatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidChar(ch) => write!(f, "could not parse char '{ch}' as a letter state"),
+            Self::WrongLength(err) => write!(f, "wrong length: {err}"),
+        }
+    }
+}
+
+impl From<crate::array::LengthError> for ParsePatternError {
+    fn from(value: crate::array::LengthError) -> Self {
+        Self::WrongLength(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParsePatternError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: ParsePatternError)
+    }
+}
@@ -0,0 +1,175 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{Array, Guess, LetterState, Word};
+
+mod error;
+pub use error::ParsePatternError;
+
+/// The [`LetterState`]s of a [`Guess`], without the guessed letters.
+///
+/// Every pattern corresponds to a unique index below 3<sup>N</sup>,
+/// so patterns can be used to index into arrays (see [`Pattern::index`]).
+///
+/// Patterns are written with one character per letter (see [`LetterState::to_char`]),
+/// e.g. `"O.o.O"`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde_derive",
+    serde(bound = "Array<LetterState, N>: serde::Serialize + for<'a> serde::Deserialize<'a>")
+)]
+pub struct Pattern<const N: usize = 5> {
+    states: Array<LetterState, N>,
+}
+
+impl<const N: usize> Pattern<N> {
+    pub fn new(states: [LetterState; N]) -> Self {
+        Self {
+            states: Array::new(states),
+        }
+    }
+
+    /// The number of distinct patterns for words of length `N`,
+    /// or `None` if it would overflow a `usize`.
+    pub const fn count() -> Option<usize> {
+        3usize.checked_pow(N as u32)
+    }
+
+    pub fn all_correct() -> Self {
+        Self::new([LetterState::Correct; N])
+    }
+
+    pub fn states(self) -> std::array::IntoIter<LetterState, N> {
+        self.states.into_iter()
+    }
+
+    pub fn get(self, index: usize) -> Option<LetterState> {
+        self.states.get(index).copied()
+    }
+
+    pub fn is_correct(self) -> bool {
+        self.states().all(LetterState::is_correct)
+    }
+
+    /// Converts this pattern to its index, reading the states as the digits
+    /// of a base-3 number with the first letter as the most significant digit.
+    ///
+    /// # Panics
+    /// Panics if [`Pattern::count`] is `None`.
+    pub fn index(self) -> usize {
+        Self::count().expect("pattern count should fit in a usize");
+
+        self.states()
+            .fold(0, |index, state| index * 3 + state.to_digit() as usize)
+    }
+
+    /// Converts an index back into a pattern (see [`Pattern::index`]).
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn from_index(mut index: usize) -> Option<Self> {
+        if Self::count().is_some_and(|count| index >= count) {
+            return None;
+        }
+
+        let mut states = [LetterState::NotPresent; N];
+
+        for state in states.iter_mut().rev() {
+            *state = LetterState::from_digit((index % 3) as u8)?;
+            index /= 3;
+        }
+
+        Some(Self::new(states))
+    }
+
+    /// Combines this pattern with the letters of a word into a [`Guess`].
+    pub fn with_word(self, word: Word<N>) -> Guess<N> {
+        let mut guess = Guess::none_present(word.letters);
+
+        for ((_, state), new) in guess.iter_mut().zip(self.states) {
+            *state = new;
+        }
+
+        guess
+    }
+}
+
+impl<const N: usize> From<Guess<N>> for Pattern<N> {
+    fn from(value: Guess<N>) -> Self {
+        Self {
+            states: Array::new(value.letters.map(|(_, state)| state)),
+        }
+    }
+}
+
+impl<const N: usize> std::fmt::Display for Pattern<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for state in self.states() {
+            f.write_char(state.to_char())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> FromStr for Pattern<N> {
+    type Err = ParsePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let states = s
+            .chars()
+            .map(|ch| LetterState::from_char(ch).ok_or(ParsePatternError::InvalidChar(ch)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            states: Array::try_from(states)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::Pattern;
+    use crate::LetterState;
+
+    #[test]
+    fn index_round_trip() {
+        for index in 0..Pattern::<5>::count().unwrap() {
+            let pattern = Pattern::<5>::from_index(index).unwrap();
+            assert_eq!(pattern.index(), index);
+        }
+
+        assert_eq!(Pattern::<5>::from_index(243), None);
+    }
+
+    #[test]
+    fn index_order() {
+        assert_eq!(Pattern::<5>::from_str(".....").unwrap().index(), 0);
+        assert_eq!(Pattern::<5>::from_str("....o").unwrap().index(), 1);
+        assert_eq!(Pattern::<5>::from_str("o....").unwrap().index(), 81);
+        assert_eq!(Pattern::<5>::all_correct().index(), 242);
+    }
+
+    #[test]
+    fn parse() {
+        let pattern = Pattern::<5>::from_str("O.o.O").unwrap();
+
+        assert_eq!(
+            pattern,
+            Pattern::new([
+                LetterState::Correct,
+                LetterState::NotPresent,
+                LetterState::WrongPlace,
+                LetterState::NotPresent,
+                LetterState::Correct,
+            ])
+        );
+        assert_eq!(pattern.to_string(), "O.o.O");
+
+        assert!(Pattern::<5>::from_str("O.o.").is_err());
+        assert!(Pattern::<5>::from_str("O.x.O").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseShareError {
+    MissingHeader,
+    InvalidHeader {
+        line: String,
+    },
+    MissingRows,
+    InvalidRow {
+        line: usize,
+        ch: char,
+    },
+    WrongWidth {
+        line: usize,
+        expected: usize,
+        got: usize,
+    },
+    ScoreMismatch {
+        score: usize,
+        rows: usize,
+    },
+}
+
+impl Error for ParseShareError {}
+
+impl Display for ParseShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader => f.write_str("share text is empty"),
+            Self::InvalidHeader { line } => write!(f, "could not find a score in header {line:?}"),
+            Self::MissingRows => f.write_str("share text has no rows"),
+            Self::InvalidRow { line, ch } => {
+                write!(f, "line {line}: unrecognized character {ch:?} in row")
+            }
+            Self::WrongWidth {
+                line,
+                expected,
+                got,
+            } => write!(f, "line {line}: expected {expected} squares, got {got}"),
+            Self::ScoreMismatch { score, rows } => {
+                write!(f, "score of {score} does not match {rows} rows")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseShareError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: ParseShareError)
+    }
+}
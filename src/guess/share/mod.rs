@@ -0,0 +1,201 @@
+//! Spoiler-free share text, like the results people post after playing Wordle:
+//!
+//! ```text
+//! kwordle 123 4/6* 💡1
+//!
+//! ⬛🟨⬛⬛⬛
+//! ⬛⬛🟩🟨⬛
+//! 🟩⬛🟩⬛🟩
+//! 🟩🟩🟩🟩🟩
+//! ```
+//!
+//! Share text can also be parsed back into a [`SharedResult`].
+
+use std::fmt::Write;
+
+use crate::{Guesses, LetterState};
+
+mod error;
+pub use error::ParseShareError;
+
+mod result;
+pub use result::SharedResult;
+
+/// Options for [`Guesses::share`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShareOptions {
+    /// The name of the game at the start of the header.
+    pub title: String,
+
+    /// Identifies the puzzle, e.g. by its number, after the title.
+    pub puzzle: Option<String>,
+
+    /// Adds an asterisk after the score.
+    pub hard_mode: bool,
+
+    /// The number of hints used, shown as `💡N` after the score if there were any.
+    pub hints: usize,
+
+    /// Uses ⬛ instead of ⬜ for letters that are not present.
+    pub dark_mode: bool,
+
+    /// Uses 🟧 and 🟦 instead of 🟩 and 🟨.
+    pub high_contrast: bool,
+}
+
+impl Default for ShareOptions {
+    fn default() -> Self {
+        Self {
+            title: "kwordle".to_owned(),
+            puzzle: None,
+            hard_mode: false,
+            hints: 0,
+            dark_mode: true,
+            high_contrast: false,
+        }
+    }
+}
+
+impl LetterState {
+    /// Returns the square used to show this state in share text.
+    pub fn emoji(self, dark_mode: bool, high_contrast: bool) -> char {
+        match self {
+            Self::Correct if high_contrast => '🟧',
+            Self::Correct => '🟩',
+            Self::WrongPlace if high_contrast => '🟦',
+            Self::WrongPlace => '🟨',
+            Self::NotPresent if dark_mode => '⬛',
+            Self::NotPresent => '⬜',
+        }
+    }
+}
+
+impl<const N: usize> Guesses<N> {
+    /// Writes the share text for these guesses.
+    ///
+    /// The score is the number of guesses if the latest one is correct, or `X` otherwise.
+    /// Without a guess limit, the maximum is written as `∞`.
+    pub fn share(&self, options: &ShareOptions) -> String {
+        let mut share = options.title.clone();
+
+        if let Some(puzzle) = &options.puzzle {
+            write!(share, " {puzzle}").expect("writing to a string should not fail");
+        }
+
+        share.push(' ');
+
+        if self.latest_is_correct() {
+            write!(share, "{}", self.count()).expect("writing to a string should not fail");
+        } else {
+            share.push('X');
+        }
+
+        share.push('/');
+
+        match self.max() {
+            Some(max) => write!(share, "{max}").expect("writing to a string should not fail"),
+            None => share.push('∞'),
+        }
+
+        if options.hard_mode {
+            share.push('*');
+        }
+
+        if options.hints > 0 {
+            write!(share, " 💡{}", options.hints).expect("writing to a string should not fail");
+        }
+
+        share.push('\n');
+
+        for guess in self {
+            share.push('\n');
+            share.extend(
+                guess
+                    .states()
+                    .map(|state| state.emoji(options.dark_mode, options.high_contrast)),
+            );
+        }
+
+        share
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::ShareOptions;
+    use crate::word::constants::word;
+    use crate::Guesses;
+
+    fn guesses(answer: &str, words: &[&str]) -> Guesses<5> {
+        let mut guesses = Guesses::new(6);
+
+        for s in words {
+            guesses.push(word(answer).guess(word(s)));
+        }
+
+        guesses
+    }
+
+    #[test]
+    fn won() {
+        let guesses = guesses("amber", &["handy", "arbor", "amber"]);
+        let options = ShareOptions {
+            puzzle: Some("123".to_owned()),
+            hard_mode: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            guesses.share(&options),
+            "kwordle 123 3/6*\n\n⬛🟨⬛⬛⬛\n🟩⬛🟩⬛🟩\n🟩🟩🟩🟩🟩"
+        );
+    }
+
+    #[test]
+    fn lost() {
+        let guesses = guesses("amber", &["handy"; 6]);
+        let share = guesses.share(&ShareOptions::default());
+
+        assert!(share.starts_with("kwordle X/6\n"));
+    }
+
+    #[test]
+    fn palettes() {
+        let guesses = guesses("amber", &["arbor", "handy"]);
+        let options = ShareOptions {
+            title: "custom".to_owned(),
+            dark_mode: false,
+            high_contrast: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            guesses.share(&options),
+            "custom X/6\n\n🟧⬜🟧⬜🟧\n⬜🟦⬜⬜⬜"
+        );
+    }
+
+    #[test]
+    fn hinted() {
+        let guesses = guesses("amber", &["amber"]);
+        let options = ShareOptions {
+            hints: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(guesses.share(&options), "kwordle 1/6 💡2\n\n🟩🟩🟩🟩🟩");
+    }
+
+    #[test]
+    fn unlimited() {
+        let mut guesses = Guesses::new(None);
+        guesses.push(word("amber").guess(word("amber")));
+
+        assert_eq!(
+            guesses.share(&ShareOptions::default()),
+            "kwordle 1/∞\n\n🟩🟩🟩🟩🟩"
+        );
+    }
+}
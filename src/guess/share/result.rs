@@ -0,0 +1,321 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{LetterState, Pattern, Word, WordsList};
+
+use super::ParseShareError;
+
+/// A result parsed from share text (see [`Guesses::share`](crate::Guesses::share)).
+///
+/// Any palette is accepted, and the squares can be mixed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedResult<const N: usize = 5> {
+    title: String,
+    puzzle: Option<String>,
+    score: Option<usize>,
+    max: Option<usize>,
+    hard_mode: bool,
+    hints: usize,
+    rows: Vec<Pattern<N>>,
+}
+
+impl<const N: usize> SharedResult<N> {
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn puzzle(&self) -> Option<&str> {
+        self.puzzle.as_deref()
+    }
+
+    /// The declared number of guesses, or `None` if the puzzle was failed.
+    pub fn score(&self) -> Option<usize> {
+        self.score
+    }
+
+    /// The declared guess limit, or `None` if there was no limit.
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// The declared number of hints used, which is 0 if none were declared.
+    pub fn hints(&self) -> usize {
+        self.hints
+    }
+
+    pub fn rows(&self) -> &[Pattern<N>] {
+        &self.rows
+    }
+
+    /// Checks whether this result could have come from a real game with an answer.
+    ///
+    /// Only the final row of a win may be correct, a win can't take more guesses
+    /// than the limit, a failed game must have used every guess,
+    /// and each row must be the pattern of some guessable word.
+    pub fn is_plausible(&self, answer: Word<N>, list: &WordsList<N>) -> bool {
+        let correct = self.rows.iter().position(|row| row.is_correct());
+
+        let finished = match self.score {
+            Some(score) => {
+                self.max.is_none_or(|max| score <= max) && correct == score.checked_sub(1)
+            }
+            None => correct.is_none() && self.max.is_none_or(|max| self.rows.len() == max),
+        };
+
+        if !finished {
+            return false;
+        }
+
+        let possible: HashSet<_> = list
+            .guessable
+            .iter()
+            .map(|word| answer.guess(*word).pattern())
+            .collect();
+
+        self.rows.iter().all(|row| possible.contains(row))
+    }
+}
+
+impl LetterState {
+    /// Parses a square from share text in any palette (see [`LetterState::emoji`]).
+    pub fn from_emoji(ch: char) -> Option<Self> {
+        match ch {
+            '🟩' | '🟧' => Some(Self::Correct),
+            '🟨' | '🟦' => Some(Self::WrongPlace),
+            '⬛' | '⬜' => Some(Self::NotPresent),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a score like `4/6*` or `X/∞` into the score, maximum and hard mode.
+fn parse_score(token: &str) -> Option<(Option<usize>, Option<usize>, bool)> {
+    let (token, hard_mode) = match token.strip_suffix('*') {
+        Some(token) => (token, true),
+        None => (token, false),
+    };
+
+    let (score, max) = token.split_once('/')?;
+
+    let score = match score {
+        "X" | "x" => None,
+        score => Some(score.parse().ok().filter(|score| *score > 0)?),
+    };
+
+    let max = match max {
+        "∞" => None,
+        max => Some(max.parse().ok()?),
+    };
+
+    Some((score, max, hard_mode))
+}
+
+impl<const N: usize> FromStr for SharedResult<N> {
+    type Err = ParseShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .skip_while(|(_, line)| line.is_empty());
+
+        let (_, header) = lines.next().ok_or(ParseShareError::MissingHeader)?;
+        let tokens: Vec<_> = header.split_whitespace().collect();
+
+        let invalid_header = || ParseShareError::InvalidHeader {
+            line: header.to_owned(),
+        };
+
+        let (position, (score, max, hard_mode)) = tokens
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find_map(|(position, token)| Some((position, parse_score(token)?)))
+            .ok_or_else(invalid_header)?;
+
+        let hints = match tokens[position + 1..]
+            .iter()
+            .find_map(|token| token.strip_prefix('💡'))
+        {
+            Some(hints) => hints.parse().map_err(|_| invalid_header())?,
+            None => 0,
+        };
+
+        let title = tokens[0].to_owned();
+        let puzzle = (position > 1).then(|| tokens[1..position].join(" "));
+
+        let mut rows = Vec::new();
+
+        for (line, text) in lines.skip_while(|(_, line)| line.is_empty()) {
+            if text.is_empty() {
+                break;
+            }
+
+            let states = text
+                .chars()
+                .filter(|ch| *ch != '\u{fe0f}')
+                .map(|ch| {
+                    LetterState::from_emoji(ch).ok_or(ParseShareError::InvalidRow { line, ch })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let states: [LetterState; N] =
+                states
+                    .try_into()
+                    .map_err(|states: Vec<_>| ParseShareError::WrongWidth {
+                        line,
+                        expected: N,
+                        got: states.len(),
+                    })?;
+
+            rows.push(Pattern::new(states));
+        }
+
+        if rows.is_empty() {
+            return Err(ParseShareError::MissingRows);
+        }
+
+        if let Some(score) = score.filter(|score| *score != rows.len()) {
+            return Err(ParseShareError::ScoreMismatch {
+                score,
+                rows: rows.len(),
+            });
+        }
+
+        Ok(Self {
+            title,
+            puzzle,
+            score,
+            max,
+            hard_mode,
+            hints,
+            rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::SharedResult;
+    use crate::guess::share::{ParseShareError, ShareOptions};
+    use crate::word::constants::word;
+    use crate::word::list::guessable::Guessable;
+    use crate::{Guesses, Pattern, WordsList};
+
+    fn pattern(s: &str) -> Pattern<5> {
+        Pattern::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut guesses = Guesses::new(6);
+        for s in ["handy", "arbor", "amber"] {
+            guesses.push(word("amber").guess(word(s)));
+        }
+
+        let options = ShareOptions {
+            puzzle: Some("1,234".to_owned()),
+            hard_mode: true,
+            hints: 1,
+            high_contrast: true,
+            ..Default::default()
+        };
+
+        let result: SharedResult<5> = guesses.share(&options).parse().unwrap();
+
+        assert_eq!(result.title(), "kwordle");
+        assert_eq!(result.puzzle(), Some("1,234"));
+        assert_eq!(result.score(), Some(3));
+        assert_eq!(result.max(), Some(6));
+        assert!(result.is_hard_mode());
+        assert_eq!(result.hints(), 1);
+        assert_eq!(
+            result.rows(),
+            [pattern(".o..."), pattern("O.O.O"), pattern("OOOOO")]
+        );
+    }
+
+    #[test]
+    fn pasted() {
+        // wordle's own format, with variation selectors and trailing chatter
+        let text = "Wordle 1,234 X/6\n\n⬛️🟨⬜⬛🟦\n⬜⬜⬜⬜⬜\n\ngg";
+        let result = SharedResult::<5>::from_str(text).unwrap();
+
+        assert_eq!(result.title(), "Wordle");
+        assert_eq!(result.score(), None);
+        assert_eq!(result.hints(), 0);
+        assert_eq!(result.rows(), [pattern(".o..o"), pattern(".....")]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            SharedResult::<5>::from_str(""),
+            Err(ParseShareError::MissingHeader)
+        );
+        assert!(matches!(
+            SharedResult::<5>::from_str("kwordle 123\n\n🟩🟩🟩🟩🟩"),
+            Err(ParseShareError::InvalidHeader { .. })
+        ));
+        assert!(matches!(
+            SharedResult::<5>::from_str("kwordle 1/6 💡a\n\n🟩🟩🟩🟩🟩"),
+            Err(ParseShareError::InvalidHeader { .. })
+        ));
+        assert_eq!(
+            SharedResult::<5>::from_str("kwordle 1/6"),
+            Err(ParseShareError::MissingRows)
+        );
+        assert_eq!(
+            SharedResult::<5>::from_str("kwordle 1/6\n\n🟩🟩🟩🟩🟩🟩"),
+            Err(ParseShareError::WrongWidth {
+                line: 3,
+                expected: 5,
+                got: 6
+            })
+        );
+        assert_eq!(
+            SharedResult::<5>::from_str("kwordle 1/6\n\n🟩🟩x🟩🟩"),
+            Err(ParseShareError::InvalidRow { line: 3, ch: 'x' })
+        );
+        assert_eq!(
+            SharedResult::<5>::from_str("kwordle 2/6\n\n🟩🟩🟩🟩🟩"),
+            Err(ParseShareError::ScoreMismatch { score: 2, rows: 1 })
+        );
+    }
+
+    #[test]
+    fn plausible() {
+        let list: WordsList<5> =
+            WordsList::from_guessable(Guessable::from_str("amber\nember\nhandy\narbor").unwrap());
+        let answer = word("amber");
+
+        let result = SharedResult::<5>::from_str("kwordle 2/6\n\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩").unwrap();
+        assert!(result.is_plausible(answer, &list));
+
+        // no word in the list gives this pattern
+        let result = SharedResult::<5>::from_str("kwordle 2/6\n\n🟨🟨🟨🟨🟨\n🟩🟩🟩🟩🟩").unwrap();
+        assert!(!result.is_plausible(answer, &list));
+
+        // the last row of a win must be correct
+        let result = SharedResult::<5>::from_str("kwordle 1/6\n\n⬛🟩🟩🟩🟩").unwrap();
+        assert!(!result.is_plausible(answer, &list));
+
+        // a loss must use every guess
+        let result = SharedResult::<5>::from_str("kwordle X/6\n\n⬛🟩🟩🟩🟩").unwrap();
+        assert!(!result.is_plausible(answer, &list));
+
+        // a win can't take more guesses than the limit
+        let rows = "⬛🟩🟩🟩🟩\n".repeat(6);
+        let result =
+            SharedResult::<5>::from_str(&format!("kwordle 7/6\n\n{rows}🟩🟩🟩🟩🟩")).unwrap();
+        assert!(!result.is_plausible(answer, &list));
+    }
+}
@@ -1,40 +1,329 @@
-use std::{
-    collections::BTreeSet,
-    ops::{Deref, DerefMut},
+use std::collections::BTreeSet;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub,
+    SubAssign,
 };
 
 use crate::Letter;
 
-#[derive(Debug, Default, PartialEq, PartialOrd, Clone, Hash)]
-pub struct LetterSet(BTreeSet<Letter>);
+static LETTERS: [Letter; Letter::COUNT] = super::LETTERS;
+
+const ALPHABET_BITS: u32 = (1 << Letter::COUNT) - 1;
+
+/// A set of [`Letter`]s, stored as a bitmask.
+///
+/// Letters are always iterated in alphabetical order.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct LetterSet(u32);
 
 impl LetterSet {
-    pub fn new() -> Self {
-        Self(BTreeSet::new())
+    /// Constructs a new, empty `LetterSet`.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Constructs a new `LetterSet` containing every letter of the alphabet.
+    pub const fn alphabet() -> Self {
+        Self(ALPHABET_BITS)
+    }
+
+    pub const fn from_letter(letter: Letter) -> Self {
+        Self(bit(letter))
+    }
+
+    /// Constructs a new `LetterSet` from an array of letters.
+    pub const fn from_letters<const N: usize>(letters: [Letter; N]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+
+        while i < N {
+            bits |= bit(letters[i]);
+            i += 1;
+        }
+
+        Self(bits)
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, letter: &Letter) -> bool {
+        self.0 & bit(*letter) != 0
+    }
+
+    /// Adds a letter to the set, returning whether it was newly inserted.
+    pub const fn insert(&mut self, letter: Letter) -> bool {
+        let inserted = !self.contains(&letter);
+        self.0 |= bit(letter);
+        inserted
+    }
+
+    /// Removes a letter from the set, returning whether it was present.
+    pub const fn remove(&mut self, letter: &Letter) -> bool {
+        let removed = self.contains(letter);
+        self.0 &= !bit(*letter);
+        removed
+    }
+
+    /// Removes a letter from the set, returning it if it was present.
+    pub const fn take(&mut self, letter: &Letter) -> Option<Letter> {
+        if self.remove(letter) {
+            Some(*letter)
+        } else {
+            None
+        }
+    }
+
+    /// Adds a letter to the set, returning it if it was already present.
+    pub const fn replace(&mut self, letter: Letter) -> Option<Letter> {
+        if self.insert(letter) {
+            None
+        } else {
+            Some(letter)
+        }
+    }
+
+    pub const fn clear(&mut self) {
+        self.0 = 0
+    }
+
+    /// Returns a reference to the letter in the set, if it is present.
+    pub fn get(&self, letter: &Letter) -> Option<&Letter> {
+        self.contains(letter).then(|| &LETTERS[letter.index()])
+    }
+
+    /// Keeps only the letters for which `f` returns `true`.
+    pub fn retain(&mut self, mut f: impl FnMut(&Letter) -> bool) {
+        for letter in *self {
+            if !f(&letter) {
+                self.remove(&letter);
+            }
+        }
+    }
+
+    /// Removes and returns the alphabetically first letter in the set.
+    pub fn pop_first(&mut self) -> Option<Letter> {
+        let first = self.first().copied()?;
+        self.remove(&first);
+        Some(first)
+    }
+
+    /// Removes and returns the alphabetically last letter in the set.
+    pub fn pop_last(&mut self) -> Option<Letter> {
+        let last = self.last().copied()?;
+        self.remove(&last);
+        Some(last)
+    }
+
+    /// Moves every letter from `other` into this set, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.0 |= other.0;
+        other.clear();
+    }
+
+    /// Splits the set in two at a letter, returning the letters from `letter` onwards.
+    pub fn split_off(&mut self, letter: &Letter) -> Self {
+        let after = Self(self.0 & !(bit(*letter) - 1));
+        self.0 &= !after.0;
+        after
+    }
+
+    /// Returns the alphabetically first letter in the set.
+    pub fn first(&self) -> Option<&Letter> {
+        self.iter().next()
+    }
+
+    /// Returns the alphabetically last letter in the set.
+    pub fn last(&self) -> Option<&Letter> {
+        self.iter().next_back()
+    }
+
+    /// Iterates over the letters in a range, in alphabetical order.
+    ///
+    /// # Panics
+    /// Panics if the range starts after it ends,
+    /// or if it starts and ends at the same letter with both bounds excluded.
+    pub fn range(&self, range: impl RangeBounds<Letter>) -> Iter<'_> {
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(start), Bound::Excluded(end)) if start == end => {
+                panic!("range start and end are equal and excluded in LetterSet")
+            }
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if start > end => panic!("range start is greater than range end in LetterSet"),
+            _ => {}
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(letter) => letter.index(),
+            Bound::Excluded(letter) => letter.index() + 1,
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(letter) => letter.index() + 1,
+            Bound::Excluded(letter) => letter.index(),
+            Bound::Unbounded => Letter::COUNT,
+        };
+
+        Iter::new(self.0 & ((1 << end) - 1) & !((1 << start) - 1))
+    }
+
+    /// Iterates over the letters in either set, in alphabetical order.
+    pub fn union<'a>(&'a self, other: &'a Self) -> Iter<'a> {
+        Iter::new(self.0 | other.0)
+    }
+
+    /// Iterates over the letters in both sets, in alphabetical order.
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Iter<'a> {
+        Iter::new(self.0 & other.0)
+    }
+
+    /// Iterates over the letters in this set but not the other, in alphabetical order.
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Iter<'a> {
+        Iter::new(self.0 & !other.0)
+    }
+
+    /// Iterates over the letters in exactly one of the sets, in alphabetical order.
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Iter<'a> {
+        Iter::new(self.0 ^ other.0)
+    }
+
+    /// Returns the letters of the alphabet that are not in this set.
+    pub const fn complement(self) -> Self {
+        Self(!self.0 & ALPHABET_BITS)
+    }
+
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.0 & other.0 == 0
     }
 
     pub fn iter(&self) -> Iter<'_> {
-        Iter::new(self.0.iter())
+        Iter::new(self.0)
+    }
+}
+
+const fn bit(letter: Letter) -> u32 {
+    1 << letter.index()
+}
+
+impl std::fmt::Debug for LetterSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LetterSet")
+            .field(&self.iter().collect::<BTreeSet<_>>())
+            .finish()
+    }
+}
+
+impl PartialOrd for LetterSet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LetterSet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl BitOr for LetterSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
     }
 }
 
-impl Deref for LetterSet {
-    type Target = BTreeSet<Letter>;
+impl BitOrAssign for LetterSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl BitAnd for LetterSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
     }
 }
 
-impl DerefMut for LetterSet {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl BitAndAssign for LetterSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0
+    }
+}
+
+impl Sub for LetterSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl SubAssign for LetterSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 &= !rhs.0
+    }
+}
+
+impl BitXor for LetterSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for LetterSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0
+    }
+}
+
+impl Not for LetterSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl From<Letter> for LetterSet {
+    fn from(value: Letter) -> Self {
+        Self::from_letter(value)
     }
 }
 
 impl FromIterator<Letter> for LetterSet {
     fn from_iter<T: IntoIterator<Item = Letter>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Letter> for LetterSet {
+    fn extend<T: IntoIterator<Item = Letter>>(&mut self, iter: T) {
+        for letter in iter {
+            self.insert(letter);
+        }
     }
 }
 
@@ -43,7 +332,7 @@ impl IntoIterator for LetterSet {
     type IntoIter = IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(self.0.into_iter())
+        IntoIter::new(self.0)
     }
 }
 
@@ -56,66 +345,267 @@ impl<'a> IntoIterator for &'a LetterSet {
     }
 }
 
-pub struct IntoIter<Base = std::collections::btree_set::IntoIter<Letter>> {
-    base: Base,
+#[derive(Clone, Debug)]
+struct Bits(u32);
+
+impl Iterator for Bits {
+    type Item = &'static Letter;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            None
+        } else {
+            let index = self.0.trailing_zeros() as usize;
+            self.0 &= self.0 - 1;
+            Some(&LETTERS[index])
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
 }
 
-impl<Base> IntoIter<Base> {
-    fn new(base: Base) -> Self {
-        Self { base }
+impl DoubleEndedIterator for Bits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            None
+        } else {
+            let index = (u32::BITS - 1 - self.0.leading_zeros()) as usize;
+            self.0 &= !(1 << index);
+            Some(&LETTERS[index])
+        }
     }
 }
 
-impl<Base: Iterator<Item = Letter>> Iterator for IntoIter<Base> {
-    type Item = Letter;
+#[derive(Clone, Debug)]
+pub struct IntoIter {
+    base: Bits,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.base.next()
+impl IntoIter {
+    fn new(bits: u32) -> Self {
+        Self { base: Bits(bits) }
     }
 }
 
-impl Deref for IntoIter {
-    type Target = std::collections::btree_set::IntoIter<Letter>;
+impl Iterator for IntoIter {
+    type Item = Letter;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.base.next().copied()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.base
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base.size_hint()
     }
 }
 
-impl DerefMut for IntoIter {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.base
+impl DoubleEndedIterator for IntoIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.base.next_back().copied()
     }
 }
 
+impl ExactSizeIterator for IntoIter {}
+
+#[derive(Clone, Debug)]
 pub struct Iter<'a> {
-    base: std::collections::btree_set::Iter<'a, Letter>,
+    base: Bits,
+    set: std::marker::PhantomData<&'a LetterSet>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(base: std::collections::btree_set::Iter<'a, Letter>) -> Self {
-        Self { base }
+impl Iter<'_> {
+    fn new(bits: u32) -> Self {
+        Self {
+            base: Bits(bits),
+            set: std::marker::PhantomData,
+        }
     }
 }
 
-impl<'a> Deref for Iter<'a> {
-    type Target = std::collections::btree_set::Iter<'a, Letter>;
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Letter;
 
-    fn deref(&self) -> &Self::Target {
-        &self.base
+    fn next(&mut self) -> Option<Self::Item> {
+        self.base.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base.size_hint()
     }
 }
 
-impl<'a> DerefMut for Iter<'a> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.base
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.base.next_back()
     }
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = &'a Letter;
+impl ExactSizeIterator for Iter<'_> {}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.deref_mut().next()
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::LetterSet;
+    use crate::Letter;
+
+    #[test]
+    fn alphabetical_order() {
+        let set = LetterSet::from_iter([Letter::Z, Letter::A, Letter::M]);
+
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Letter::A, Letter::M, Letter::Z]
+        );
+        assert_eq!(
+            set.into_iter().rev().collect::<Vec<_>>(),
+            [Letter::Z, Letter::M, Letter::A]
+        );
+        assert_eq!(format!("{set:?}"), "LetterSet({A, M, Z})");
+    }
+
+    #[test]
+    fn alphabet() {
+        let alphabet = LetterSet::alphabet();
+
+        assert_eq!(alphabet.len(), 26);
+        assert_eq!(alphabet.first(), Some(&Letter::A));
+        assert_eq!(alphabet.last(), Some(&Letter::Z));
+        assert!(alphabet.complement().is_empty());
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = LetterSet::new();
+
+        assert!(set.insert(Letter::Q));
+        assert!(!set.insert(Letter::Q));
+        assert!(set.contains(&Letter::Q));
+        assert!(set.remove(&Letter::Q));
+        assert!(!set.remove(&Letter::Q));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn algebra() {
+        const ABC: LetterSet = LetterSet::from_letters([Letter::A, Letter::B, Letter::C]);
+        const BCD: LetterSet = LetterSet::from_letters([Letter::B, Letter::C, Letter::D]);
+
+        assert_eq!(
+            ABC | BCD,
+            LetterSet::from_letters([Letter::A, Letter::B, Letter::C, Letter::D])
+        );
+        assert_eq!(ABC & BCD, LetterSet::from_letters([Letter::B, Letter::C]));
+        assert_eq!(ABC - BCD, LetterSet::from(Letter::A));
+        assert_eq!((!ABC).len(), 23);
+        assert!((ABC & BCD).is_subset(&ABC));
+        assert!(ABC.is_disjoint(&!ABC));
+    }
+
+    #[test]
+    fn borrowing_algebra() {
+        let abc = LetterSet::from_letters([Letter::A, Letter::B, Letter::C]);
+        let bcd = LetterSet::from_letters([Letter::B, Letter::C, Letter::D]);
+
+        assert_eq!(
+            abc.union(&bcd).copied().collect::<Vec<_>>(),
+            [Letter::A, Letter::B, Letter::C, Letter::D]
+        );
+        assert_eq!(
+            abc.intersection(&bcd).copied().collect::<Vec<_>>(),
+            [Letter::B, Letter::C]
+        );
+        assert_eq!(
+            abc.difference(&bcd).copied().collect::<Vec<_>>(),
+            [Letter::A]
+        );
+        assert_eq!(
+            abc.symmetric_difference(&bcd).copied().collect::<Vec<_>>(),
+            [Letter::A, Letter::D]
+        );
+        assert_eq!(abc ^ bcd, LetterSet::from_letters([Letter::A, Letter::D]));
+    }
+
+    #[test]
+    fn get_take_replace() {
+        let mut set = LetterSet::from_letters([Letter::A, Letter::B]);
+
+        assert_eq!(set.get(&Letter::A), Some(&Letter::A));
+        assert_eq!(set.get(&Letter::C), None);
+        assert_eq!(set.take(&Letter::A), Some(Letter::A));
+        assert_eq!(set.take(&Letter::A), None);
+        assert_eq!(set.replace(Letter::B), Some(Letter::B));
+        assert_eq!(set.replace(Letter::C), None);
+        assert_eq!(set, LetterSet::from_letters([Letter::B, Letter::C]));
+    }
+
+    #[test]
+    fn retain() {
+        let mut set = LetterSet::alphabet();
+        set.retain(|letter| *letter <= Letter::C);
+
+        assert_eq!(
+            set,
+            LetterSet::from_letters([Letter::A, Letter::B, Letter::C])
+        );
+    }
+
+    #[test]
+    fn pop() {
+        let mut set = LetterSet::from_letters([Letter::C, Letter::A, Letter::Z]);
+
+        assert_eq!(set.pop_first(), Some(Letter::A));
+        assert_eq!(set.pop_last(), Some(Letter::Z));
+        assert_eq!(set.pop_last(), Some(Letter::C));
+        assert_eq!(set.pop_first(), None);
+    }
+
+    #[test]
+    fn append() {
+        let mut set = LetterSet::from_letters([Letter::A]);
+        let mut other = LetterSet::from_letters([Letter::B]);
+        set.append(&mut other);
+
+        assert_eq!(set, LetterSet::from_letters([Letter::A, Letter::B]));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn range() {
+        let set = LetterSet::from_letters([Letter::A, Letter::C, Letter::E, Letter::Z]);
+        assert_eq!(
+            set.range(Letter::C..Letter::Z).copied().collect::<Vec<_>>(),
+            [Letter::C, Letter::E]
+        );
+        assert_eq!(
+            set.range(Letter::C..=Letter::Z)
+                .copied()
+                .collect::<Vec<_>>(),
+            [Letter::C, Letter::E, Letter::Z]
+        );
+        assert_eq!(
+            set.range(..Letter::C).copied().collect::<Vec<_>>(),
+            [Letter::A]
+        );
+        assert_eq!(set.range(..).count(), 4);
+        assert_eq!(set.range(Letter::B..Letter::B).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_backwards() {
+        LetterSet::alphabet().range(Letter::Z..Letter::A);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut set = LetterSet::from_letters([Letter::A, Letter::C, Letter::E]);
+        let after = set.split_off(&Letter::C);
+
+        assert_eq!(set, LetterSet::from_letters([Letter::A]));
+        assert_eq!(after, LetterSet::from_letters([Letter::C, Letter::E]));
     }
 }
@@ -22,7 +22,12 @@ macro_rules! enum_letter {
             $($name),+
         }
 
+        const LETTERS: [Letter; Letter::COUNT] = [$(Letter::$name),+];
+
         impl Letter {
+            /// The number of letters in the alphabet.
+            pub const COUNT: usize = [$($ch),+].len();
+
             pub fn to_char(&self) -> char {
                 match self {
                     $( Self::$name => $ch ),+
@@ -36,16 +41,6 @@ macro_rules! enum_letter {
                 }
             }
         }
-
-        pub fn alphabet_set() -> LetterSet {
-            let mut set = LetterSet::new();
-
-            $(
-                set.insert( Letter::$name );
-            )+
-
-            set
-        }
     };
 }
 
@@ -78,10 +73,29 @@ enum_letter! {
     Z 'z'
 }
 
+/// Constructs a new [`LetterSet`] containing every letter of the alphabet.
+pub const fn alphabet_set() -> LetterSet {
+    LetterSet::alphabet()
+}
+
 impl Letter {
     pub fn from_char(ch: char) -> Option<Self> {
         Self::from_char_lowercase(ch.to_ascii_lowercase())
     }
+
+    /// Returns the position of this letter in the alphabet, starting from 0.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the letter at a position in the alphabet, starting from 0.
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some(LETTERS[index])
+        } else {
+            None
+        }
+    }
 }
 
 impl From<Letter> for char {
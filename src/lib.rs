@@ -17,6 +17,12 @@ pub use guess::Guesses;
 #[doc(inline)]
 pub use guess::LetterState;
 
+#[doc(inline)]
+pub use guess::Knowledge;
+
+#[doc(inline)]
+pub use guess::Pattern;
+
 pub mod word;
 
 #[doc(inline)]
@@ -30,5 +36,16 @@ pub use word::WordsList;
 
 pub mod classic;
 
+pub mod solver;
+
+pub mod daily;
+
+pub mod stats;
+
+#[cfg(feature = "ansi")]
+pub mod render;
+
 mod array;
 use array::Array;
+
+mod rng;
@@ -0,0 +1,198 @@
+//! Draws guesses, boards and keyboards as coloured tiles for a terminal,
+//! using ANSI escape codes.
+//!
+//! ```text
+//!  A   R   B   O   R
+//!  _   _   _   _   _
+//! ```
+//!
+//! The [`Palette`] can be switched to colours that don't rely on telling
+//! green from yellow, or to plain text for terminals without colour.
+
+use crate::guess::{KeyboardState, Layout};
+use crate::{Guess, Guesses, Letter, LetterState};
+
+const RESET: &str = "\x1b[0m";
+
+/// The colours used to draw tiles.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub enum Palette {
+    /// Green for correct, yellow for the wrong place and grey for not present.
+    #[default]
+    Normal,
+
+    /// Orange for correct and blue for the wrong place, like the high contrast share text.
+    ColourBlind,
+
+    /// No escape codes: correct letters are `[A]`, wrong place letters are `(A)`,
+    /// and letters that are not present are lowercase.
+    NoColour,
+}
+
+impl Palette {
+    /// Returns the escape code that starts a tile, or `None` for no colour.
+    fn escape(self, state: LetterState) -> Option<&'static str> {
+        match (self, state) {
+            (Self::NoColour, _) => None,
+            (Self::Normal, LetterState::Correct) => Some("\x1b[1;30;42m"),
+            (Self::Normal, LetterState::WrongPlace) => Some("\x1b[1;30;43m"),
+            (Self::ColourBlind, LetterState::Correct) => Some("\x1b[1;30;48;5;208m"),
+            (Self::ColourBlind, LetterState::WrongPlace) => Some("\x1b[1;37;44m"),
+            (_, LetterState::NotPresent) => Some("\x1b[1;37;100m"),
+        }
+    }
+}
+
+/// Renders tiles with a [`Palette`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Renderer {
+    palette: Palette,
+}
+
+impl Renderer {
+    pub fn new(palette: Palette) -> Self {
+        Self { palette }
+    }
+
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette
+    }
+
+    /// Draws a single letter, with its state if it has one.
+    pub fn tile(&self, letter: Letter, state: Option<LetterState>) -> String {
+        let upper = letter.to_char().to_ascii_uppercase();
+
+        let Some(state) = state else {
+            return format!(" {upper} ");
+        };
+
+        match self.palette.escape(state) {
+            Some(escape) => format!("{escape} {upper} {RESET}"),
+            None => match state {
+                LetterState::Correct => format!("[{upper}]"),
+                LetterState::WrongPlace => format!("({upper})"),
+                LetterState::NotPresent => format!(" {} ", letter.to_char()),
+            },
+        }
+    }
+
+    /// Draws a guess as a row of tiles.
+    pub fn guess<const N: usize>(&self, guess: Guess<N>) -> String {
+        Self::row(
+            guess
+                .into_iter()
+                .map(|(letter, state)| self.tile(letter, Some(state))),
+        )
+    }
+
+    /// Draws every guess on its own row, followed by an empty row for each guess that is left.
+    ///
+    /// Without a guess limit, there are no empty rows.
+    pub fn board<const N: usize>(&self, guesses: &Guesses<N>) -> String {
+        let empty = guesses
+            .max()
+            .unwrap_or_default()
+            .saturating_sub(guesses.count());
+
+        guesses
+            .iter()
+            .map(|guess| self.guess(*guess))
+            .chain(std::iter::repeat_n(
+                Self::row(std::iter::repeat_n(" _ ".to_owned(), N)),
+                empty,
+            ))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Draws a keyboard in a [`Layout`], with each row indented a little more than the last.
+    pub fn keyboard(&self, keyboard: &KeyboardState, layout: Layout) -> String {
+        keyboard
+            .rows(layout)
+            .enumerate()
+            .map(|(indent, row)| {
+                let tiles = Self::row(row.map(|(letter, state)| self.tile(letter, state)));
+                format!("{}{tiles}", "  ".repeat(indent))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn row(tiles: impl Iterator<Item = String>) -> String {
+        tiles.collect::<Vec<_>>().join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Palette, Renderer};
+    use crate::guess::Layout;
+    use crate::word::constants::word;
+    use crate::Guesses;
+
+    #[test]
+    fn no_colour() {
+        let renderer = Renderer::new(Palette::NoColour);
+        let guess = word("amber").guess(word("arbor"));
+
+        assert_eq!(renderer.guess(guess), "[A]  r  [B]  o  [R]");
+    }
+
+    #[test]
+    fn colour() {
+        let renderer = Renderer::new(Palette::Normal);
+        let guess = word("amber").guess(word("handy"));
+
+        assert_eq!(
+            renderer.guess(guess),
+            [
+                "\x1b[1;37;100m H \x1b[0m",
+                "\x1b[1;30;43m A \x1b[0m",
+                "\x1b[1;37;100m N \x1b[0m",
+                "\x1b[1;37;100m D \x1b[0m",
+                "\x1b[1;37;100m Y \x1b[0m",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn board() {
+        let renderer = Renderer::new(Palette::NoColour);
+        let mut guesses = Guesses::new(3);
+        guesses.push(word("amber").guess(word("amber")));
+
+        assert_eq!(
+            renderer.board(&guesses),
+            "[A] [M] [B] [E] [R]\n _   _   _   _   _ \n _   _   _   _   _ "
+        );
+
+        assert_eq!(renderer.board(&Guesses::<5>::new(None)), "");
+    }
+
+    #[test]
+    fn keyboard() {
+        let renderer = Renderer::new(Palette::NoColour);
+        let mut guesses = Guesses::new(6);
+        guesses.push(word("amber").guess(word("arbor")));
+
+        let keyboard = renderer.keyboard(&guesses.keyboard(), Layout::Qwerty);
+        let lines: Vec<_> = keyboard.lines().collect();
+
+        assert_eq!(
+            lines,
+            [
+                " Q   W   E  [R]  T   Y   U   I   o   P ",
+                "  [A]  S   D   F   G   H   J   K   L ",
+                "     Z   X   C   V  [B]  N   M ",
+            ]
+        );
+    }
+}
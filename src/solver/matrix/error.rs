@@ -0,0 +1,65 @@
+use std::{error::Error, fmt::Display};
+
+use crate::letter::ParseLetterError;
+
+#[derive(Debug)]
+pub enum ReadMatrixError {
+    Io(std::io::Error),
+    InvalidHeader,
+    UnsupportedVersion(u8),
+    WrongWordLength { expected: usize, got: usize },
+    ParseLetter(ParseLetterError),
+    InvalidPattern(u16),
+}
+
+impl Error for ReadMatrixError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::ParseLetter(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReadMatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read matrix: {err}"),
+            Self::InvalidHeader => f.write_str("input is not a pattern matrix"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported pattern matrix version {version}")
+            }
+            Self::WrongWordLength { expected, got } => {
+                write!(f, "expected words of length {expected}, got {got}")
+            }
+            Self::ParseLetter(err) => write!(f, "failed to parse letter: {err}"),
+            Self::InvalidPattern(index) => write!(f, "invalid pattern index {index}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadMatrixError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ParseLetterError> for ReadMatrixError {
+    fn from(value: ParseLetterError) -> Self {
+        Self::ParseLetter(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadMatrixError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: ReadMatrixError)
+    }
+}
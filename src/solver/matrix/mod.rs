@@ -0,0 +1,383 @@
+use std::io::{Read, Write};
+
+use crate::{Letter, Letters, Pattern, Word, WordsList};
+
+mod error;
+pub use error::ReadMatrixError;
+
+const MAGIC: &[u8; 4] = b"KWPM";
+const VERSION: u8 = 1;
+
+/// A precomputed table of the [`Pattern`] for every guessable word against every answer.
+///
+/// Building the table is expensive, but lookups are much cheaper than calling
+/// [`Word::guess`]. A built table can be saved with [`PatternMatrix::write_to`]
+/// and loaded again with [`PatternMatrix::read_from`].
+///
+/// Patterns are stored by index as `u16`s, so words can be at most 10 letters long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMatrix<const N: usize = 5> {
+    guesses: Box<[Word<N>]>,
+    answers: Box<[Word<N>]>,
+    patterns: Box<[u16]>,
+}
+
+impl<const N: usize> PatternMatrix<N> {
+    /// Builds the table for a [`WordsList`], using as many threads as are available.
+    ///
+    /// # Panics
+    /// Panics if `N` is greater than 10.
+    pub fn new(list: &WordsList<N>) -> Self {
+        let threads = std::thread::available_parallelism().map_or(1, usize::from);
+        Self::with_threads(list, threads)
+    }
+
+    /// Builds the table for a [`WordsList`], split across the given number of threads.
+    ///
+    /// # Panics
+    /// Panics if `N` is greater than 10.
+    pub fn with_threads(list: &WordsList<N>, threads: usize) -> Self {
+        assert!(
+            Self::fits(),
+            "pattern matrix only supports words of up to 10 letters"
+        );
+
+        // lookups use binary search, so the words must be sorted without duplicates
+        let mut guesses: Vec<Word<N>> = list.guessable.iter().copied().collect();
+        guesses.sort_unstable();
+        guesses.dedup();
+        let guesses = guesses.into_boxed_slice();
+
+        let mut answers = list.answers.as_slice().to_vec();
+        answers.sort_unstable();
+        answers.dedup();
+        let answers = answers.into_boxed_slice();
+
+        let mut patterns = vec![0; guesses.len() * answers.len()].into_boxed_slice();
+
+        if !answers.is_empty() {
+            let rows_per_thread = guesses.len().div_ceil(threads.max(1)).max(1);
+
+            std::thread::scope(|scope| {
+                let rows = patterns.chunks_mut(rows_per_thread * answers.len());
+
+                for (rows, guesses) in rows.zip(guesses.chunks(rows_per_thread)) {
+                    let answers = &answers;
+
+                    scope.spawn(move || {
+                        let cells = rows.chunks_mut(answers.len());
+
+                        for (row, guess) in cells.zip(guesses) {
+                            for (cell, answer) in row.iter_mut().zip(answers.iter()) {
+                                *cell = answer.guess(*guess).pattern().index() as u16;
+                            }
+                        }
+                    });
+                }
+            });
+        }
+
+        Self {
+            guesses,
+            answers,
+            patterns,
+        }
+    }
+
+    fn fits() -> bool {
+        Pattern::<N>::count().is_some_and(|count| count <= u16::MAX as usize + 1)
+    }
+
+    /// The guessable words, in sorted order.
+    pub fn guesses(&self) -> &[Word<N>] {
+        &self.guesses
+    }
+
+    /// The answers, in sorted order.
+    pub fn answers(&self) -> &[Word<N>] {
+        &self.answers
+    }
+
+    pub fn guess_index(&self, guess: Word<N>) -> Option<usize> {
+        self.guesses.binary_search(&guess).ok()
+    }
+
+    pub fn answer_index(&self, answer: Word<N>) -> Option<usize> {
+        self.answers.binary_search(&answer).ok()
+    }
+
+    /// Looks up the pattern for a guess against an answer,
+    /// if both are in the table.
+    pub fn get(&self, guess: Word<N>, answer: Word<N>) -> Option<Pattern<N>> {
+        let index = self.pattern_index(self.guess_index(guess)?, self.answer_index(answer)?);
+        Pattern::from_index(index)
+    }
+
+    /// Looks up the indices of several answers at once, for use with [`PatternMatrix::pattern_indices`].
+    ///
+    /// Returns `None` if any of the answers are not in the table.
+    pub fn answer_indices(&self, answers: &[Word<N>]) -> Option<Vec<usize>> {
+        answers
+            .iter()
+            .map(|answer| self.answer_index(*answer))
+            .collect()
+    }
+
+    /// Returns the [`Pattern::index`] of a guess against each of the answers
+    /// at the given indices, or `None` if the guess is not in the table.
+    ///
+    /// # Panics
+    /// The iterator will panic if any of the answer indices are out of bounds.
+    pub fn pattern_indices<'a>(
+        &'a self,
+        guess: Word<N>,
+        answer_indices: &'a [usize],
+    ) -> Option<impl Iterator<Item = usize> + 'a> {
+        let row = self.guess_index(guess)?;
+
+        Some(
+            answer_indices
+                .iter()
+                .map(move |answer| self.pattern_index(row, *answer)),
+        )
+    }
+
+    fn pattern_index(&self, guess: usize, answer: usize) -> usize {
+        self.patterns[guess * self.answers.len() + answer] as usize
+    }
+
+    /// Writes this table in a compact binary format.
+    ///
+    /// # Errors
+    /// Returns any error from the writer.
+    pub fn write_to(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&(N as u32).to_le_bytes())?;
+        writer.write_all(&(self.guesses.len() as u32).to_le_bytes())?;
+        writer.write_all(&(self.answers.len() as u32).to_le_bytes())?;
+
+        for word in self.guesses.iter().chain(self.answers.iter()) {
+            let bytes = word.letters.map(|letter| letter.to_char() as u8);
+            writer.write_all(&bytes)?;
+        }
+
+        for pattern in self.patterns.iter() {
+            writer.write_all(&pattern.to_le_bytes())?;
+        }
+
+        writer.flush()
+    }
+
+    /// Reads a table written by [`PatternMatrix::write_to`].
+    ///
+    /// The words in the table are not checked against any [`WordsList`].
+    ///
+    /// # Errors
+    /// Returns a [`ReadMatrixError`] if reading fails,
+    /// or if the data is not a valid table for words of length `N`.
+    pub fn read_from(mut reader: impl Read) -> Result<Self, ReadMatrixError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(ReadMatrixError::InvalidHeader);
+        }
+
+        let [version] = read_bytes(&mut reader)?;
+        if version != VERSION {
+            return Err(ReadMatrixError::UnsupportedVersion(version));
+        }
+
+        let len = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+        if len != N || !Self::fits() {
+            return Err(ReadMatrixError::WrongWordLength {
+                expected: N,
+                got: len,
+            });
+        }
+
+        let guesses = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+        let answers = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+
+        let guesses = read_sorted_words(&mut reader, guesses, "guesses")?;
+        let answers = read_sorted_words(&mut reader, answers, "answers")?;
+
+        // the counts come from the file, so memory is only used as rows are actually read
+        let count = Pattern::<N>::count().expect("already checked that patterns fit");
+        let mut row = vec![0; answers.len() * 2];
+        let mut patterns = Vec::new();
+
+        for _ in 0..guesses.len() {
+            reader.read_exact(&mut row)?;
+
+            for bytes in row.chunks_exact(2) {
+                let pattern = u16::from_le_bytes([bytes[0], bytes[1]]);
+
+                if (pattern as usize) >= count {
+                    return Err(ReadMatrixError::InvalidPattern(pattern));
+                }
+
+                patterns.push(pattern);
+            }
+        }
+
+        Ok(Self {
+            guesses,
+            answers,
+            patterns: patterns.into_boxed_slice(),
+        })
+    }
+}
+
+fn read_bytes<const LEN: usize>(reader: &mut impl Read) -> std::io::Result<[u8; LEN]> {
+    let mut bytes = [0; LEN];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads words that must be strictly sorted, since lookups use binary search.
+fn read_sorted_words<const N: usize>(
+    reader: &mut impl Read,
+    count: usize,
+    name: &str,
+) -> Result<Box<[Word<N>]>, ReadMatrixError> {
+    let words = read_words(reader, count)?;
+
+    if words.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{name} are not strictly sorted"),
+        )
+        .into());
+    }
+
+    Ok(words)
+}
+
+fn read_words<const N: usize>(
+    reader: &mut impl Read,
+    count: usize,
+) -> Result<Box<[Word<N>]>, ReadMatrixError> {
+    (0..count)
+        .map(|_| {
+            let bytes: [u8; N] = read_bytes(reader)?;
+            let mut letters = [Letter::A; N];
+
+            for (letter, byte) in letters.iter_mut().zip(bytes) {
+                *letter = Letter::try_from(byte as char)?;
+            }
+
+            Ok(unsafe { Word::new_unchecked(Letters::new(letters)) })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{PatternMatrix, ReadMatrixError};
+    use crate::word::constants;
+    use crate::word::list::{answers::Answers, guessable::Guessable};
+    use crate::WordsList;
+
+    fn words_list() -> WordsList<5> {
+        constants::words_list_exclusive(
+            "abuse\nsonar\nthree\neerie\nspeed",
+            "amber\nember\number\nthere\ngeese",
+        )
+    }
+
+    #[test]
+    fn matches_guess() {
+        let list = words_list();
+        let matrix = PatternMatrix::with_threads(&list, 3);
+
+        assert_eq!(matrix, PatternMatrix::with_threads(&list, 1));
+
+        for guess in &list.guessable {
+            for answer in &list.answers {
+                assert_eq!(
+                    matrix.get(*guess, *answer),
+                    Some(answer.guess(*guess).pattern())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let matrix = PatternMatrix::new(&words_list());
+
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        assert_eq!(PatternMatrix::read_from(bytes.as_slice()).unwrap(), matrix);
+
+        assert!(matches!(
+            PatternMatrix::<6>::read_from(bytes.as_slice()),
+            Err(ReadMatrixError::WrongWordLength {
+                expected: 6,
+                got: 5
+            })
+        ));
+
+        assert!(matches!(
+            PatternMatrix::<5>::read_from(&b"nope"[..]),
+            Err(ReadMatrixError::InvalidHeader)
+        ));
+
+        assert!(matches!(
+            PatternMatrix::<5>::read_from(&bytes[..bytes.len() - 1]),
+            Err(ReadMatrixError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn duplicate_answers() {
+        let list: WordsList<5> = WordsList::new(
+            Guessable::from_str("abuse\nsonar").unwrap(),
+            Answers::from_str("amber\namber\nember").unwrap(),
+        );
+        let matrix = PatternMatrix::new(&list);
+
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        assert_eq!(PatternMatrix::read_from(bytes.as_slice()).unwrap(), matrix);
+    }
+
+    #[test]
+    fn unsorted() {
+        let matrix = PatternMatrix::new(&words_list());
+
+        let mut bytes = Vec::new();
+        matrix.write_to(&mut bytes).unwrap();
+
+        // swap the first two guesses, "abuse" and "amber", which starts 17 bytes in
+        bytes[17..27].rotate_left(5);
+
+        match PatternMatrix::<5>::read_from(bytes.as_slice()) {
+            Err(ReadMatrixError::Io(err)) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::InvalidData)
+            }
+            other => panic!("expected an error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn huge_header() {
+        let mut bytes = b"KWPM\x01".to_vec();
+        bytes.extend(5u32.to_le_bytes());
+        bytes.extend(u32::MAX.to_le_bytes());
+        bytes.extend(u32::MAX.to_le_bytes());
+        bytes.extend(b"abuse");
+
+        assert!(matches!(
+            PatternMatrix::<5>::read_from(bytes.as_slice()),
+            Err(ReadMatrixError::Io(_))
+        ));
+    }
+}
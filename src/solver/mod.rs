@@ -0,0 +1,269 @@
+//! Picks guesses by how much they are expected to reveal about the answer.
+//!
+//! Each guessable word is scored by the [Shannon entropy] of the feedback it would
+//! produce against every answer still consistent with previous guesses.
+//! A higher entropy means the feedback is expected to narrow down the answer more.
+//!
+//! [Shannon entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+
+use std::collections::HashMap;
+
+use crate::{Guess, Guesses, Knowledge, Pattern, Word, WordsList};
+
+pub mod matrix;
+pub use matrix::PatternMatrix;
+
+/// A word paired with the expected information from guessing it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoredGuess<const N: usize = 5> {
+    word: Word<N>,
+    entropy: f64,
+    candidate: bool,
+}
+
+impl<const N: usize> ScoredGuess<N> {
+    pub fn word(self) -> Word<N> {
+        self.word
+    }
+
+    /// The expected information from guessing this word, in bits.
+    pub fn entropy(self) -> f64 {
+        self.entropy
+    }
+
+    /// Whether this word could still be the answer.
+    pub fn is_candidate(self) -> bool {
+        self.candidate
+    }
+}
+
+/// The largest number of patterns to count in an array rather than a map.
+const MAX_DENSE_PATTERNS: usize = 3usize.pow(10);
+
+/// Calculates the expected information from guessing a word, in bits,
+/// assuming each of the candidates is equally likely to be the answer.
+pub fn entropy<const N: usize>(word: Word<N>, candidates: &[Word<N>]) -> f64 {
+    let patterns = candidates.iter().map(|answer| answer.guess(word).pattern());
+
+    match Pattern::<N>::count() {
+        Some(count) if count <= MAX_DENSE_PATTERNS => {
+            entropy_of_indices(patterns.map(Pattern::index), count, candidates.len())
+        }
+        _ => {
+            let mut buckets: HashMap<Pattern<N>, usize> = HashMap::new();
+
+            for pattern in patterns {
+                *buckets.entry(pattern).or_default() += 1;
+            }
+
+            entropy_of_counts(buckets.into_values(), candidates.len())
+        }
+    }
+}
+
+fn entropy_of_indices(indices: impl IntoIterator<Item = usize>, count: usize, total: usize) -> f64 {
+    let mut buckets = vec![0; count];
+
+    for index in indices {
+        buckets[index] += 1;
+    }
+
+    entropy_of_counts(buckets, total)
+}
+
+fn entropy_of_counts(counts: impl IntoIterator<Item = usize>, total: usize) -> f64 {
+    let total = total as f64;
+
+    counts
+        .into_iter()
+        .filter(|count| *count > 0)
+        .map(|count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Tracks the remaining answers for a [`WordsList`] and ranks guesses against them.
+#[derive(Debug, Clone)]
+pub struct Solver<'list, const N: usize = 5> {
+    list: &'list WordsList<N>,
+    candidates: Vec<Word<N>>,
+    matrix: Option<&'list PatternMatrix<N>>,
+}
+
+impl<'list, const N: usize> Solver<'list, N> {
+    /// Constructs a new `Solver` where every answer in the list is a candidate.
+    pub fn new(list: &'list WordsList<N>) -> Self {
+        Self {
+            list,
+            candidates: list.answers.as_slice().to_vec(),
+            matrix: None,
+        }
+    }
+
+    /// Constructs a new `Solver` with only the answers consistent with some guesses.
+    pub fn from_guesses(list: &'list WordsList<N>, guesses: &Guesses<N>) -> Self {
+        Self {
+            list,
+            candidates: list.remaining_answers(guesses),
+            matrix: None,
+        }
+    }
+
+    /// Uses a precomputed [`PatternMatrix`] to score guesses.
+    ///
+    /// Words that are not in the matrix are still scored with [`Word::guess`].
+    pub fn set_matrix(&mut self, matrix: &'list PatternMatrix<N>) {
+        self.matrix = Some(matrix)
+    }
+
+    /// Removes the candidates that are inconsistent with a guess.
+    pub fn update(&mut self, guess: Guess<N>) {
+        let knowledge = Knowledge::from(guess);
+        self.candidates
+            .retain(|candidate| knowledge.is_consistent(candidate));
+    }
+
+    pub fn candidates(&self) -> &[Word<N>] {
+        &self.candidates
+    }
+
+    /// Calculates the expected information from guessing a word (see [`entropy`]).
+    pub fn entropy(&self, word: Word<N>) -> f64 {
+        self.entropy_with(word, self.matrix_columns().as_deref())
+    }
+
+    fn matrix_columns(&self) -> Option<Vec<usize>> {
+        self.matrix?.answer_indices(&self.candidates)
+    }
+
+    fn entropy_with(&self, word: Word<N>, columns: Option<&[usize]>) -> f64 {
+        let total = self.candidates.len();
+
+        self.matrix
+            .zip(columns)
+            .and_then(|(matrix, columns)| {
+                let count = Pattern::<N>::count()?;
+                let indices = matrix.pattern_indices(word, columns)?;
+                Some(entropy_of_indices(indices, count, total))
+            })
+            .unwrap_or_else(|| entropy(word, &self.candidates))
+    }
+
+    /// Scores the given words, ordered from most to least informative.
+    ///
+    /// Words with equal scores are ordered with candidates first, then alphabetically.
+    pub fn rank<'w>(&self, words: impl IntoIterator<Item = &'w Word<N>>) -> Vec<ScoredGuess<N>> {
+        let columns = self.matrix_columns();
+
+        let mut scored: Vec<_> = words
+            .into_iter()
+            .map(|word| ScoredGuess {
+                word: *word,
+                entropy: self.entropy_with(*word, columns.as_deref()),
+                candidate: self.candidates.contains(word),
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.entropy
+                .total_cmp(&a.entropy)
+                .then(b.candidate.cmp(&a.candidate))
+                .then(a.word.cmp(&b.word))
+        });
+
+        scored
+    }
+
+    /// Scores every guessable word in the list (see [`Solver::rank`]).
+    pub fn ranked(&self) -> Vec<ScoredGuess<N>> {
+        self.rank(&self.list.guessable)
+    }
+
+    /// Scores only the words that could still be the answer (see [`Solver::rank`]).
+    pub fn ranked_candidates(&self) -> Vec<ScoredGuess<N>> {
+        self.rank(&self.candidates)
+    }
+
+    /// Returns the most informative guessable word, if there are any candidates left.
+    pub fn best(&self) -> Option<ScoredGuess<N>> {
+        if self.candidates.is_empty() {
+            None
+        } else {
+            self.ranked().first().copied()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{entropy, PatternMatrix, Solver};
+    use crate::word::constants::{self, word};
+    use crate::WordsList;
+
+    fn words_list() -> WordsList<5> {
+        constants::words_list_exclusive("abuse\nsonar", "amber\nember\number")
+    }
+
+    #[test]
+    fn entropy_of_split() {
+        let candidates = [word("amber"), word("ember"), word("umber")];
+
+        // "ember" and "umber" give the same feedback for "amber"
+        let expected = -(1.0 / 3.0 * (1.0f64 / 3.0).log2() + 2.0 / 3.0 * (2.0f64 / 3.0).log2());
+        assert!((entropy(word("amber"), &candidates) - expected).abs() < 1e-9);
+
+        // "abuse" tells all three apart
+        assert!((entropy(word("abuse"), &candidates) - 3f64.log2()).abs() < 1e-9);
+
+        // "colic" tells none of them apart
+        assert_eq!(entropy(word("colic"), &candidates), 0.0);
+    }
+
+    #[test]
+    fn ranked() {
+        let list = words_list();
+        let solver = Solver::new(&list);
+
+        let ranked: Vec<_> = solver.ranked().into_iter().map(|s| s.word()).collect();
+        assert_eq!(
+            ranked,
+            vec![
+                word("abuse"),
+                word("amber"),
+                word("ember"),
+                word("umber"),
+                word("sonar")
+            ]
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        let list = words_list();
+        let matrix = PatternMatrix::new(&list);
+
+        let mut solver = Solver::new(&list);
+        let expected = solver.ranked();
+
+        solver.set_matrix(&matrix);
+        assert_eq!(solver.ranked(), expected);
+    }
+
+    #[test]
+    fn update() {
+        let list = words_list();
+        let mut solver = Solver::new(&list);
+
+        solver.update(word("umber").guess(word("amber")));
+        assert_eq!(solver.candidates(), &[word("ember"), word("umber")]);
+
+        // with two candidates left, guessing one of them is as good as anything else
+        let best = solver.best().unwrap();
+        assert!(best.is_candidate());
+        assert_eq!(best.entropy(), 1.0);
+    }
+}
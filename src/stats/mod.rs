@@ -0,0 +1,389 @@
+//! Player statistics, like the Wordle stats screen.
+//!
+//! [`Stats`] are fed finished games and keep separate [`ModeStats`]
+//! for all games, normal mode games and hard mode games.
+
+use crate::game::GameStatus;
+use crate::Game;
+
+/// Statistics for one kind of game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModeStats {
+    current_streak: u32,
+    max_streak: u32,
+
+    /// The number of wins in `index + 1` guesses,
+    /// at least as long as the guess limit.
+    distribution: Vec<u32>,
+    failures: u32,
+}
+
+impl ModeStats {
+    /// The guess limit of a classic game, used by [`ModeStats::new`].
+    pub const DEFAULT_MAX_GUESSES: usize = 6;
+
+    /// Constructs new, empty `ModeStats` for games of up to 6 guesses.
+    pub fn new() -> Self {
+        Self::with_max_guesses(Self::DEFAULT_MAX_GUESSES)
+    }
+
+    /// Constructs new, empty `ModeStats` with a distribution
+    /// from 1 guess up to `max_guesses`.
+    ///
+    /// Wins in more guesses than this are still recorded, and extend the distribution.
+    pub fn with_max_guesses(max_guesses: usize) -> Self {
+        Self {
+            current_streak: 0,
+            max_streak: 0,
+            distribution: vec![0; max_guesses],
+            failures: 0,
+        }
+    }
+
+    pub fn played(&self) -> u32 {
+        self.wins() + self.failures
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.distribution.iter().sum()
+    }
+
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+
+    /// Returns the percentage of games won, from 0 to 100.
+    ///
+    /// If no games have been played, this is 0.
+    pub fn win_percentage(&self) -> f64 {
+        let played = self.played();
+
+        if played == 0 {
+            0.0
+        } else {
+            f64::from(self.wins()) * 100.0 / f64::from(played)
+        }
+    }
+
+    /// Returns the number of games won in a row, up to the latest game.
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak
+    }
+
+    pub fn max_streak(&self) -> u32 {
+        self.max_streak
+    }
+
+    /// Returns the number of wins in each number of guesses,
+    /// where index 0 is the number of wins in 1 guess.
+    ///
+    /// The distribution goes up to the guess limit, or the longest win if that is longer.
+    /// See [`ModeStats::failures`] for the games that were lost.
+    pub fn distribution(&self) -> &[u32] {
+        &self.distribution
+    }
+
+    /// Returns the number of wins in exactly `guesses` guesses.
+    pub fn won_in(&self, guesses: usize) -> u32 {
+        guesses
+            .checked_sub(1)
+            .and_then(|index| self.distribution.get(index))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Records a win in the given number of guesses.
+    ///
+    /// Returns `false` without recording anything if `guesses` is 0.
+    pub fn record_win(&mut self, guesses: usize) -> bool {
+        if guesses == 0 {
+            return false;
+        }
+
+        self.extend_to(guesses);
+        self.distribution[guesses - 1] += 1;
+        self.current_streak += 1;
+        self.max_streak = self.max_streak.max(self.current_streak);
+
+        true
+    }
+
+    pub fn record_loss(&mut self) {
+        self.failures += 1;
+        self.current_streak = 0;
+    }
+
+    /// Records a finished game's status.
+    ///
+    /// Returns `false` without recording anything if the game is still in progress,
+    /// or if it claims to be won in 0 guesses.
+    pub fn record(&mut self, status: GameStatus) -> bool {
+        match status {
+            GameStatus::InProgress => false,
+            GameStatus::Won { guesses } => self.record_win(guesses),
+            GameStatus::Lost => {
+                self.record_loss();
+                true
+            }
+        }
+    }
+
+    /// Combines statistics from another device into these ones.
+    ///
+    /// Counts are added together. Streaks can't be combined exactly
+    /// without knowing when each game was played, so the longer of each is kept.
+    pub fn merge(&mut self, other: &Self) {
+        self.current_streak = self.current_streak.max(other.current_streak);
+        self.max_streak = self.max_streak.max(other.max_streak);
+        self.failures += other.failures;
+
+        self.extend_to(other.distribution.len());
+
+        for (count, other) in self.distribution.iter_mut().zip(&other.distribution) {
+            *count += other;
+        }
+    }
+
+    /// Makes sure the distribution goes up to at least `guesses`.
+    fn extend_to(&mut self, guesses: usize) {
+        if self.distribution.len() < guesses {
+            self.distribution.resize(guesses, 0);
+        }
+    }
+}
+
+impl Default for ModeStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Statistics for all games, with breakdowns for normal and hard mode.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_derive", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    all: ModeStats,
+    normal: ModeStats,
+    hard: ModeStats,
+}
+
+impl Stats {
+    /// Constructs new, empty `Stats` for games of up to 6 guesses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs new, empty `Stats` with distributions from 1 guess up to `max_guesses`
+    /// (see [`ModeStats::with_max_guesses`]).
+    pub fn with_max_guesses(max_guesses: usize) -> Self {
+        Self {
+            all: ModeStats::with_max_guesses(max_guesses),
+            normal: ModeStats::with_max_guesses(max_guesses),
+            hard: ModeStats::with_max_guesses(max_guesses),
+        }
+    }
+
+    /// Returns the statistics for every game, regardless of mode.
+    pub fn all(&self) -> &ModeStats {
+        &self.all
+    }
+
+    pub fn normal(&self) -> &ModeStats {
+        &self.normal
+    }
+
+    pub fn hard(&self) -> &ModeStats {
+        &self.hard
+    }
+
+    /// Returns the statistics for either hard or normal mode.
+    pub fn mode(&self, hard_mode: bool) -> &ModeStats {
+        if hard_mode {
+            &self.hard
+        } else {
+            &self.normal
+        }
+    }
+
+    /// Records a finished game's status, played in hard mode or not.
+    ///
+    /// Returns `false` without recording anything if the game is still in progress,
+    /// or if it claims to be won in 0 guesses.
+    pub fn record(&mut self, status: GameStatus, hard_mode: bool) -> bool {
+        let mode = if hard_mode {
+            &mut self.hard
+        } else {
+            &mut self.normal
+        };
+
+        mode.record(status) && self.all.record(status)
+    }
+
+    /// Records a finished [`Game`], extending the distribution to its guess limit.
+    ///
+    /// Returns `false` without recording anything if the game is still in progress.
+    pub fn record_game<const N: usize>(&mut self, game: &Game<N>) -> bool {
+        if !self.record(game.status(), game.is_hard_mode()) {
+            return false;
+        }
+
+        if let Some(max) = game.guesses().max() {
+            for mode in [&mut self.all, &mut self.normal, &mut self.hard] {
+                mode.extend_to(max);
+            }
+        }
+
+        true
+    }
+
+    /// Combines statistics from another device into these ones.
+    ///
+    /// See [`ModeStats::merge`].
+    pub fn merge(&mut self, other: &Self) {
+        self.all.merge(&other.all);
+        self.normal.merge(&other.normal);
+        self.hard.merge(&other.hard);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{ModeStats, Stats};
+    use crate::game::GameStatus;
+    use crate::word::list::guessable::Guessable;
+    use crate::{Game, Word, WordsList};
+
+    #[test]
+    fn streaks() {
+        let mut stats = ModeStats::new();
+
+        assert!(stats.record_win(3));
+        assert!(stats.record_win(4));
+        stats.record_loss();
+        assert!(stats.record_win(3));
+        assert!(!stats.record_win(0));
+
+        assert_eq!(stats.played(), 4);
+        assert_eq!(stats.wins(), 3);
+        assert_eq!(stats.failures(), 1);
+        assert_eq!(stats.win_percentage(), 75.0);
+        assert_eq!(stats.current_streak(), 1);
+        assert_eq!(stats.max_streak(), 2);
+        assert_eq!(stats.distribution(), &[0, 0, 2, 1, 0, 0]);
+        assert_eq!(stats.won_in(3), 2);
+        assert_eq!(stats.won_in(0), 0);
+        assert_eq!(stats.won_in(6), 0);
+    }
+
+    #[test]
+    fn modes() {
+        let mut stats = Stats::new();
+
+        assert!(stats.record(GameStatus::Won { guesses: 2 }, false));
+        assert!(stats.record(GameStatus::Lost, true));
+        assert!(!stats.record(GameStatus::InProgress, true));
+        assert!(!stats.record(GameStatus::Won { guesses: 0 }, true));
+
+        assert_eq!(stats.all().played(), 2);
+        assert_eq!(stats.normal().wins(), 1);
+        assert_eq!(stats.hard().failures(), 1);
+        assert_eq!(stats.mode(true), stats.hard());
+        assert_eq!(stats.all().current_streak(), 0);
+        assert_eq!(stats.normal().current_streak(), 1);
+    }
+
+    #[test]
+    fn game() {
+        let list: WordsList<5> =
+            WordsList::from_guessable(Guessable::from_str("amber\nsonar").unwrap());
+        let mut game = Game::new(Word::from_str(&list, "amber").unwrap(), list, 6);
+        let mut stats = Stats::new();
+
+        game.guess_str("sonar").unwrap();
+        assert!(!stats.record_game(&game));
+
+        game.guess_str("amber").unwrap();
+        assert!(stats.record_game(&game));
+        assert_eq!(stats.normal().won_in(2), 1);
+        assert_eq!(stats.normal().distribution(), &[0, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn distribution_limit() {
+        let list: WordsList<5> =
+            WordsList::from_guessable(Guessable::from_str("amber\nsonar").unwrap());
+        let mut game = Game::new(Word::from_str(&list, "amber").unwrap(), list, 8);
+        game.guess_str("amber").unwrap();
+
+        let mut stats = Stats::with_max_guesses(3);
+        assert_eq!(stats.all().distribution(), &[0, 0, 0]);
+
+        stats.record_game(&game);
+        assert_eq!(stats.all().distribution(), &[1, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut mode = ModeStats::with_max_guesses(2);
+        mode.record_win(4);
+        assert_eq!(mode.distribution(), &[0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn merge() {
+        let mut phone = ModeStats::new();
+        phone.record_win(2);
+        phone.record_win(2);
+
+        let mut laptop = ModeStats::with_max_guesses(7);
+        laptop.record_win(5);
+        laptop.record_loss();
+        laptop.record_win(1);
+
+        phone.merge(&laptop);
+
+        assert_eq!(phone.played(), 5);
+        assert_eq!(phone.wins(), 4);
+        assert_eq!(phone.failures(), 1);
+        assert_eq!(phone.distribution(), &[1, 2, 0, 0, 1, 0, 0]);
+        assert_eq!(phone.current_streak(), 2);
+        assert_eq!(phone.max_streak(), 2);
+    }
+
+    #[test]
+    fn merge_stats() {
+        let mut phone = Stats::new();
+        phone.record(GameStatus::Won { guesses: 3 }, false);
+        phone.record(GameStatus::Lost, true);
+
+        let mut laptop = Stats::new();
+        laptop.record(GameStatus::Won { guesses: 1 }, true);
+        laptop.record(GameStatus::Won { guesses: 3 }, true);
+        laptop.record(GameStatus::Lost, false);
+
+        phone.merge(&laptop);
+
+        let totals = |stats: &ModeStats| {
+            (
+                stats.played(),
+                stats.wins(),
+                stats.failures(),
+                stats.distribution().to_vec(),
+            )
+        };
+
+        assert_eq!(totals(phone.all()), (5, 3, 2, vec![1, 0, 2, 0, 0, 0]));
+        assert_eq!(totals(phone.normal()), (2, 1, 1, vec![0, 0, 1, 0, 0, 0]));
+        assert_eq!(totals(phone.hard()), (3, 2, 1, vec![1, 0, 1, 0, 0, 0]));
+        assert_eq!(
+            phone.all().played(),
+            phone.normal().played() + phone.hard().played()
+        );
+
+        assert_eq!(phone.all().max_streak(), 2);
+        assert_eq!(phone.normal().current_streak(), 1);
+        assert_eq!(phone.hard().current_streak(), 2);
+    }
+}
@@ -19,3 +19,33 @@ pub fn MUMMY() -> Word<5> {
 pub fn TUMMY() -> Word<5> {
     unsafe { Word::from_str_unchecked("tummy").expect("hard-coded word should be valid") }
 }
+
+/// Parses a hard-coded word for a test, without checking it against a list.
+#[cfg(test)]
+pub(crate) fn word(s: &str) -> Word<5> {
+    unsafe { Word::from_str_unchecked(s).expect("hard-coded word should be valid") }
+}
+
+/// Builds a list for a test where every guessable word is an answer.
+#[cfg(test)]
+pub(crate) fn words_list(guessable: &str) -> crate::WordsList<5> {
+    use std::str::FromStr;
+
+    crate::WordsList::from_guessable(
+        crate::word::list::guessable::Guessable::from_str(guessable)
+            .expect("hard-coded list should be valid"),
+    )
+}
+
+/// Builds a list for a test where the answers are not in the guessable words.
+#[cfg(test)]
+pub(crate) fn words_list_exclusive(guessable: &str, answers: &str) -> crate::WordsList<5> {
+    use std::str::FromStr;
+
+    crate::WordsList::new_exclusive(
+        crate::word::list::guessable::Guessable::from_str(guessable)
+            .expect("hard-coded list should be valid"),
+        crate::word::list::answers::Answers::from_str(answers)
+            .expect("hard-coded list should be valid"),
+    )
+}
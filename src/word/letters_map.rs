@@ -2,58 +2,92 @@ use crate::letter::Letter;
 
 use std::collections::HashMap;
 
-#[derive(Default, Debug, Clone)]
+/// Counts how many times each [`Letter`] appears.
+///
+/// Counts are stored in a fixed array indexed by [`Letter::index`],
+/// so a `LettersMap` never allocates and most methods are `const`.
+/// Counts saturate at [`u8::MAX`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LettersMap {
-    pub(crate) hash_map: HashMap<Letter, usize>,
+    counts: [u8; Letter::COUNT],
 }
 
 impl LettersMap {
-    pub fn new() -> Self {
-        HashMap::new().into()
+    pub const fn new() -> Self {
+        Self {
+            counts: [0; Letter::COUNT],
+        }
     }
 
-    pub fn count_letter(&self, letter: Letter) -> usize {
-        self.hash_map.get(&letter).copied().unwrap_or_default()
+    /// Counts the letters in an array.
+    pub const fn from_letters<const N: usize>(letters: &[Letter; N]) -> Self {
+        let mut map = Self::new();
+        let mut i = 0;
+
+        while i < N {
+            map.increment(letters[i]);
+            i += 1;
+        }
+
+        map
     }
 
-    pub fn contains_letter(&self, letter: Letter) -> bool {
-        self.hash_map.contains_key(&letter)
+    pub const fn count_letter(&self, letter: Letter) -> usize {
+        self.counts[letter.index()] as usize
     }
 
-    pub fn insert(&mut self, letter: Letter) {
-        self.hash_map.insert(letter, 1);
+    pub const fn contains_letter(&self, letter: Letter) -> bool {
+        self.counts[letter.index()] > 0
     }
 
-    pub fn increment(&mut self, letter: Letter) {
-        if let Some(count) = self.hash_map.get_mut(&letter) {
-            *count += 1;
+    /// Sets the count of a letter, removing it from the map if the count is 0.
+    pub const fn set_count(&mut self, letter: Letter, count: usize) {
+        self.counts[letter.index()] = if count > u8::MAX as usize {
+            u8::MAX
         } else {
-            self.insert(letter)
-        }
+            count as u8
+        };
     }
 
-    pub fn decrement(&mut self, letter: Letter) -> Option<usize> {
-        if self.contains_letter(letter) {
-            if self.count_letter(letter) == 1 {
-                self.hash_map.remove(&letter);
-                Some(0)
-            } else {
-                let count = self
-                    .hash_map
-                    .get_mut(&letter)
-                    .expect("already checked that the map contains this letter");
-                *count -= 1; // will never be 0
-                Some(*count)
-            }
-        } else {
+    /// Iterates over the letters in the map and their counts, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (Letter, usize)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .filter_map(|(index, count)| Some((Letter::from_index(index)?, *count as usize)))
+    }
+
+    pub const fn insert(&mut self, letter: Letter) {
+        self.set_count(letter, 1)
+    }
+
+    pub const fn increment(&mut self, letter: Letter) {
+        let count = &mut self.counts[letter.index()];
+        *count = count.saturating_add(1);
+    }
+
+    pub const fn decrement(&mut self, letter: Letter) -> Option<usize> {
+        let count = &mut self.counts[letter.index()];
+
+        if *count == 0 {
             None
+        } else {
+            *count -= 1;
+            Some(*count as usize)
         }
     }
 }
 
 impl From<HashMap<Letter, usize>> for LettersMap {
     fn from(value: HashMap<Letter, usize>) -> Self {
-        Self { hash_map: value }
+        let mut map = Self::new();
+
+        for (letter, count) in value {
+            map.set_count(letter, count);
+        }
+
+        map
     }
 }
 
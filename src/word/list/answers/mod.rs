@@ -1,8 +1,9 @@
 use std::{collections::HashSet, str::FromStr};
 
-use crate::{letter::letters::ParseLettersError, Word};
+use crate::word::words::ParseLineError;
+use crate::{Knowledge, Word};
 
-use super::guessable::Guessable;
+use super::{guessable::Guessable, ParseWordsListError};
 
 type Base<const N: usize> = Box<[Word<N>]>;
 
@@ -40,8 +41,14 @@ impl<const N: usize> Answers<N> {
         Self::new_unchecked(base)
     }
 
+    /// Constructs new `Answers` from every word in a [`Guessable`], in sorted order.
     pub fn from_guessable(guessable: &Guessable<N>) -> Self {
-        unsafe { Self::from_iter_unchecked(guessable.into_iter().copied()) }
+        let mut words: Vec<_> = guessable.into_iter().copied().collect();
+
+        // sorting keeps the order (and so seeded choices) the same between runs
+        words.sort_unstable();
+
+        unsafe { Self::from_iter_unchecked(words) }
     }
 }
 
@@ -80,6 +87,18 @@ impl<const N: usize> Answers<N> {
         self.into_iter().collect()
     }
 
+    /// Returns the answers that are still consistent with the given feedback,
+    /// which can be a single [`Guess`](crate::Guess), a set of [`Guesses`](crate::Guesses),
+    /// or a [`Knowledge`] built from either.
+    pub fn remaining(&self, feedback: impl Into<Knowledge<N>>) -> Vec<Word<N>> {
+        let knowledge = feedback.into();
+
+        self.into_iter()
+            .filter(|word| knowledge.is_consistent(word))
+            .copied()
+            .collect()
+    }
+
     #[cfg(feature = "rand")]
     pub fn random_with<R>(&self, rng: &mut R) -> Word<N>
     where
@@ -99,10 +118,61 @@ impl<const N: usize> Answers<N> {
     }
 }
 
+impl<const N: usize> Answers<N> {
+    /// Parses the lines of a string that are words, skipping the lines that aren't
+    /// (see [`parse_lenient`](crate::word::words::parse_lenient)).
+    ///
+    /// # Errors
+    /// Returns [`ParseWordsListError::EmptyInput`] if no line is a word.
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<ParseLineError>), ParseWordsListError> {
+        let (words, errors) = crate::word::words::parse_lenient(s);
+        let answers = Self::try_new(words).ok_or(ParseWordsListError::EmptyInput)?;
+
+        Ok((answers, errors))
+    }
+}
+
 impl<const N: usize> FromStr for Answers<N> {
-    type Err = ParseLettersError;
+    type Err = ParseWordsListError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(unsafe { Self::new_unchecked(crate::word::words::parse_from_str(s)?) })
+        let words = crate::word::words::parse_from_str(s)?;
+        Self::try_new(words).ok_or(ParseWordsListError::EmptyInput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+
+    use super::Answers;
+    use crate::word::constants::word;
+    use crate::Guesses;
+
+    #[test]
+    fn lenient() {
+        let (answers, errors) = Answers::<5>::from_str_lenient("amber\nsonar!\nember").unwrap();
+
+        assert_eq!(answers.as_slice(), [word("amber"), word("ember")]);
+        assert_eq!(errors[0].line(), 2);
+
+        assert!(Answers::<5>::from_str_lenient("sonar!").is_err());
+        assert!(Answers::<5>::from_str("amber\nsonar!").is_err());
+    }
+
+    #[test]
+    fn remaining() {
+        let answers = Answers::<5>::from_str("amber\nembed\nember\number\nsonar").unwrap();
+        let answer = word("umber");
+
+        let guess = answer.guess(word("amber"));
+        assert_eq!(answers.remaining(guess), vec![word("ember"), word("umber")]);
+
+        let mut guesses = Guesses::new(None);
+        guesses.push(guess);
+        guesses.push(answer.guess(word("ember")));
+        assert_eq!(answers.remaining(&guesses), vec![word("umber")]);
     }
 }
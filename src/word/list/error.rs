@@ -1,13 +1,44 @@
-use crate::letter::letters::ParseLettersError;
+use crate::word::words::ParseLineError;
 
 #[derive(Debug, Clone)]
 pub enum ParseWordsListError {
-    ParseLetters(ParseLettersError),
+    ParseLine(ParseLineError),
     EmptyInput,
 }
 
-impl From<ParseLettersError> for ParseWordsListError {
-    fn from(value: ParseLettersError) -> Self {
-        Self::ParseLetters(value)
+impl From<ParseLineError> for ParseWordsListError {
+    fn from(value: ParseLineError) -> Self {
+        Self::ParseLine(value)
+    }
+}
+
+impl std::error::Error for ParseWordsListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ParseLine(err) => Some(err),
+            Self::EmptyInput => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ParseWordsListError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParseLine(err) => write!(f, "failed to parse words: {err}"),
+            Self::EmptyInput => f.write_str("no words in list"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseWordsListError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: ParseWordsListError)
     }
 }
@@ -1,6 +1,7 @@
 use std::{collections::HashSet, str::FromStr};
 
-use crate::{Letters, Word};
+use crate::word::words::ParseLineError;
+use crate::{Knowledge, Letters, Word};
 
 use super::{answers::Answers, ParseWordsListError};
 
@@ -32,6 +33,21 @@ impl<const N: usize> Guessable<N> {
     }
 }
 
+impl<const N: usize> Guessable<N> {
+    /// Parses the lines of a string that are words, skipping the lines that aren't
+    /// (see [`parse_lenient`](crate::word::words::parse_lenient)).
+    ///
+    /// # Errors
+    /// Returns [`ParseWordsListError::EmptyInput`] if no line is a word.
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<ParseLineError>), ParseWordsListError> {
+        let (words, errors) = crate::word::words::parse_lenient(s);
+        let guessable =
+            Self::from_words(words.into_vec()).ok_or(ParseWordsListError::EmptyInput)?;
+
+        Ok((guessable, errors))
+    }
+}
+
 impl<const N: usize> FromStr for Guessable<N> {
     type Err = ParseWordsListError;
 
@@ -54,6 +70,21 @@ impl<const N: usize> Guessable<N> {
         self.into_iter()
     }
 
+    /// Returns the guessable words that are still consistent with the given feedback,
+    /// in sorted order (see [`Answers::remaining`]).
+    pub fn remaining(&self, feedback: impl Into<Knowledge<N>>) -> Vec<Word<N>> {
+        let knowledge = feedback.into();
+
+        let mut words: Vec<_> = self
+            .iter()
+            .filter(|word| knowledge.is_consistent(word))
+            .copied()
+            .collect();
+
+        words.sort_unstable();
+        words
+    }
+
     pub fn includes_answers(&self, answers: &Answers<N>) -> bool {
         self.answers_intersection(answers) == answers.to_set()
     }
@@ -49,6 +49,15 @@ impl<const N: usize> WordsList<N> {
         new(guessable, answers)
     }
 
+    /// Returns the answers that are still consistent with the given feedback
+    /// (see [`Answers::remaining`](answers::Answers::remaining)).
+    pub fn remaining_answers(
+        &self,
+        feedback: impl Into<crate::Knowledge<N>>,
+    ) -> Vec<crate::Word<N>> {
+        self.answers.remaining(feedback)
+    }
+
     #[cfg(feature = "classic_words")]
     pub fn classic() -> WordsList<5> {
         crate::classic::words_list()
@@ -16,7 +16,7 @@ pub mod list;
 mod validity;
 pub use validity::WordValidity;
 
-mod words;
+pub mod words;
 
 #[cfg(feature = "serde")]
 mod serde;
@@ -37,10 +37,18 @@ pub struct Word<const LEN: usize = 5> {
 }
 
 impl<const LEN: usize> Word<LEN> {
-    pub unsafe fn new_unchecked(letters: Letters<LEN>) -> Self {
+    /// Constructs a new `Word` without checking it against a [`WordsList`].
+    ///
+    /// # Safety
+    /// The letters should form a word that exists in the list it will be used with.
+    pub const unsafe fn new_unchecked(letters: Letters<LEN>) -> Self {
         Self { letters }
     }
 
+    /// Parses a string slice into a `Word` without checking it against a [`WordsList`].
+    ///
+    /// # Safety
+    /// See [`Word::new_unchecked`].
     #[allow(dead_code)]
     pub unsafe fn from_str_unchecked(s: &str) -> Result<Self, ParseLettersError> {
         let letters = Letters::from_str(s)?;
@@ -83,32 +91,42 @@ impl<const LEN: usize> Word<LEN> {
     }
 
     /// Constructs a new [`LettersMap`] from this word's letters.
-    pub fn letters_map(self) -> letters_map::LettersMap {
-        letters_map::LettersMap::from_iter(self.letters)
+    pub const fn letters_map(self) -> letters_map::LettersMap {
+        letters_map::LettersMap::from_letters(self.letters.as_array())
     }
 
     /// Checks the letters of another `Word` against this one,
     /// returning a [`Guess`](super::Guess) with the status of each guessed letter.
-    pub fn guess(self, word: Self) -> super::guess::Guess<LEN> {
-        let mut guess = crate::guess::Guess::none_present(word.letters);
+    ///
+    /// This does not allocate, and can be used in `const` contexts.
+    pub const fn guess(self, word: Self) -> super::guess::Guess<LEN> {
+        let answer = self.letters.as_array();
+        let guessed = word.letters.as_array();
+
         let mut map = self.letters_map();
+        let mut states = [LetterState::NotPresent; LEN];
 
-        for ((letter, state), answer) in guess.iter_mut().zip(self.letters.into_iter()) {
-            if letter == answer {
-                *state = LetterState::Correct;
-                map.decrement(letter);
+        let mut i = 0;
+        while i < LEN {
+            if guessed[i].index() == answer[i].index() {
+                states[i] = LetterState::Correct;
+                map.decrement(guessed[i]);
             }
+
+            i += 1;
         }
 
-        for (letter, state) in guess.iter_mut() {
-            // i don't know why i need to check for correctness again? whatev
-            if map.contains_letter(letter) && *state != LetterState::Correct {
-                *state = LetterState::WrongPlace;
-                map.decrement(letter);
+        let mut i = 0;
+        while i < LEN {
+            if !matches!(states[i], LetterState::Correct) && map.contains_letter(guessed[i]) {
+                states[i] = LetterState::WrongPlace;
+                map.decrement(guessed[i]);
             }
+
+            i += 1;
         }
 
-        guess
+        crate::guess::Guess::from_states(word.letters, states)
     }
 
     /// Parses a string slice into a `Word` with the given [`WordsList`],
@@ -163,7 +181,7 @@ impl<'w, const LEN: usize> PartialEq<&'w Self> for Word<LEN> {
     }
 }
 
-impl<'w, const LEN: usize> PartialEq<Word<LEN>> for &'w Word<LEN> {
+impl<const LEN: usize> PartialEq<Word<LEN>> for &Word<LEN> {
     fn eq(&self, other: &Word<LEN>) -> bool {
         (*self).eq(other)
     }
@@ -192,6 +210,25 @@ mod tests {
         assert_ne!(words::SONAR(), "amber")
     }
 
+    #[test]
+    fn const_guess() {
+        use crate::{Guess, Letter, LetterState, Letters, Word};
+
+        const ANSWER: Word<3> =
+            unsafe { Word::new_unchecked(Letters::new([Letter::E, Letter::B, Letter::B])) };
+        const GUESS: Guess<3> = ANSWER
+            .guess(unsafe { Word::new_unchecked(Letters::new([Letter::B, Letter::E, Letter::B])) });
+
+        assert_eq!(
+            GUESS.states().collect::<Vec<_>>(),
+            [
+                LetterState::WrongPlace,
+                LetterState::WrongPlace,
+                LetterState::Correct
+            ]
+        );
+    }
+
     mod letters_map {
         use crate::Letter;
 
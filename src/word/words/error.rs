@@ -0,0 +1,59 @@
+use crate::letter::letters::ParseLettersError;
+
+/// Describes a line of a word list that could not be parsed.
+#[derive(Debug, Clone)]
+pub struct ParseLineError {
+    line: usize,
+    text: String,
+    reason: ParseLettersError,
+}
+
+impl ParseLineError {
+    pub(crate) fn new(line: usize, text: &str, reason: ParseLettersError) -> Self {
+        Self {
+            line,
+            text: text.to_owned(),
+            reason,
+        }
+    }
+
+    /// Returns the line number, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the text of the line.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns why the line is not a word, such as a bad character or the wrong length.
+    pub fn reason(&self) -> &ParseLettersError {
+        &self.reason
+    }
+}
+
+impl std::error::Error for ParseLineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.reason)
+    }
+}
+
+impl std::fmt::Display for ParseLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}: {:?}", self.line, self.reason, self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseLineError;
+
+    #[test]
+    fn impl_error() {
+        use assert_impl::assert_impl;
+        use std::error::Error;
+
+        assert_impl!(Error: ParseLineError)
+    }
+}
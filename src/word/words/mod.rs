@@ -1,14 +1,109 @@
+//! Parsing word lists with one word per line.
+
 use std::str::FromStr;
 
-use crate::{letter::letters::ParseLettersError, Letters, Word};
+use crate::{Letters, Word};
+
+mod error;
+pub use error::ParseLineError;
+
+/// Parses every line of a string into a `Word`.
+///
+/// # Errors
+/// Returns a [`ParseLineError`] for the first line that is not a word.
+pub fn parse_from_str<const N: usize>(s: &str) -> Result<Box<[Word<N>]>, ParseLineError> {
+    parse_lines(s).collect()
+}
+
+/// Parses every line of a string that is a word, and collects the errors for the lines that aren't.
+///
+/// This is useful for lists that are mostly valid, but may have a few typos.
+pub fn parse_lenient<const N: usize>(s: &str) -> (Box<[Word<N>]>, Vec<ParseLineError>) {
+    let mut words = Vec::new();
+    let mut errors = Vec::new();
+
+    for result in parse_lines(s) {
+        match result {
+            Ok(word) => words.push(word),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    (words.into_boxed_slice(), errors)
+}
+
+/// Parses each line of a string into a `Word`, yielding one result per line.
+pub fn parse_lines<const N: usize>(
+    s: &str,
+) -> impl Iterator<Item = Result<Word<N>, ParseLineError>> + '_ {
+    s.lines()
+        .enumerate()
+        .map(|(index, text)| parse_line(index, text))
+}
+
+/// Like [`parse_lines`], but trims whitespace from each line and skips blank lines,
+/// which hand-kept lists often have.
+///
+/// Line numbers in errors still count every line, including the skipped ones.
+pub fn parse_lines_lenient<const N: usize>(
+    s: &str,
+) -> impl Iterator<Item = Result<Word<N>, ParseLineError>> + '_ {
+    s.lines().enumerate().filter_map(|(index, text)| {
+        let text = text.trim();
+
+        (!text.is_empty()).then(|| parse_line(index, text))
+    })
+}
+
+fn parse_line<const N: usize>(index: usize, text: &str) -> Result<Word<N>, ParseLineError> {
+    Letters::from_str(text)
+        .map(|letters| unsafe { Word::new_unchecked(letters) })
+        .map_err(|err| ParseLineError::new(index + 1, text, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{parse_from_str, parse_lenient, parse_lines_lenient};
+    use crate::letter::letters::ParseLettersError;
+    use crate::word::constants::word;
+
+    #[test]
+    fn first_error() {
+        let err = parse_from_str::<5>("amber\nsonar\nam8er\nhand").unwrap_err();
+
+        assert_eq!(err.line(), 3);
+        assert_eq!(err.text(), "am8er");
+        assert!(matches!(err.reason(), ParseLettersError::ParseLetter(_)));
+        assert_eq!(
+            err.to_string(),
+            "line 3: failed to parse letter: could not parse char '8' as a letter: \"am8er\""
+        );
+    }
+
+    #[test]
+    fn lenient() {
+        let (words, errors) = parse_lenient::<5>("amber\nam8er\nsonar\nhand");
+
+        assert_eq!(&*words, [word("amber"), word("sonar")]);
+        assert_eq!(
+            errors.iter().map(|err| err.line()).collect::<Vec<_>>(),
+            [2, 4]
+        );
+        assert!(matches!(
+            errors[1].reason(),
+            ParseLettersError::WrongLength(_)
+        ));
+    }
 
-pub fn parse_from_str<const N: usize>(s: &str) -> Result<Box<[Word<N>]>, ParseLettersError> {
-    let words = s
-        .lines()
-        .map(Letters::from_str)
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .map(|letters| unsafe { Word::new_unchecked(letters) });
+    #[test]
+    fn lines_lenient() {
+        let results: Vec<_> = parse_lines_lenient::<5>("amber \n\n\tSonar\r\nam8er\n").collect();
 
-    Ok(words.collect())
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &word("amber"));
+        assert_eq!(results[1].as_ref().unwrap(), &word("sonar"));
+        assert_eq!(results[2].as_ref().unwrap_err().line(), 4);
+    }
 }
@@ -0,0 +1,107 @@
+mod common;
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use common::words_file;
+
+fn kwordle(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_kwordle"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn play(name: &str, extra: &[&str], stdin: &str) -> String {
+    let words = words_file(&format!("{name}-words.txt"), "amber\nsonar\nhandy\narbor");
+    let answers = words_file(&format!("{name}-answers.txt"), "amber");
+
+    let mut args = vec![
+        "--words",
+        words.to_str().unwrap(),
+        "--answers",
+        answers.to_str().unwrap(),
+        "--palette",
+        "none",
+        "--seed",
+        "1",
+    ];
+    args.extend(extra);
+
+    let output = kwordle(&args, stdin);
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn won() {
+    let stdout = play("won", &[], "sonar\nzzzzz\nAMBER\n");
+
+    assert!(stdout.starts_with("seed: 1\n"));
+    assert!(stdout.contains(" s   o   n  (A) [R]"));
+    assert!(stdout.contains("Solved in 2/6!"));
+    assert!(stdout.contains("kwordle 2/6\n\n⬛⬛⬛🟨🟩\n🟩🟩🟩🟩🟩"));
+}
+
+#[test]
+fn lost() {
+    let stdout = play("lost", &["--max-guesses", "1"], "handy\n");
+
+    assert!(stdout.contains("Out of guesses. The answer was AMBER."));
+    assert!(stdout.contains("kwordle X/1"));
+}
+
+#[test]
+fn hard_mode() {
+    let stdout = play("hard", &["--hard"], "arbor\nsonar\n");
+
+    assert!(stdout.contains("1st letter must be A"));
+    assert!(stdout.contains("Gave up. The answer was AMBER."));
+}
+
+#[test]
+fn answers_not_guessable() {
+    let words = words_file("overlap-words.txt", "amber\nsonar");
+    let answers = words_file("overlap-answers.txt", "amber\nhandy");
+
+    let output = kwordle(
+        &[
+            "--words",
+            words.to_str().unwrap(),
+            "--answers",
+            answers.to_str().unwrap(),
+            "--palette",
+            "none",
+        ],
+        "handy\namber\n",
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Solved in"));
+}
+
+#[test]
+fn usage_errors() {
+    let output = kwordle(&["--bogus"], "");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = kwordle(&["--length", "4"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("needs --words"));
+}
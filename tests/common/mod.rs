@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+/// Writes a words file for a test, in a directory unique to this test binary and run.
+pub fn words_file(name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "kwordle-{}-{}",
+        env!("CARGO_CRATE_NAME"),
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use std::process::{Command, Output};
+
+use common::words_file;
+
+fn lists(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_kwordle-lists"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn validate() {
+    let path = words_file("validate.txt", "amber\nsonar\nam8er\nsonar\nhand\n");
+    let output = lists(&["validate", path.to_str().unwrap()]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("validate.txt: line 3: "));
+    assert!(stderr(&output).contains("validate.txt: line 4: duplicate of line 2: \"sonar\""));
+    assert!(stderr(&output).contains("validate.txt: line 5: "));
+    assert!(stdout(&output).ends_with("3 words, 2 unique, 2 bad lines\n"));
+}
+
+#[test]
+fn normalise() {
+    let path = words_file("normalise.txt", "SONAR\namber\nsonar\n");
+    let output = lists(&["normalise", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "amber\nsonar\n");
+}
+
+#[test]
+fn normalise_messy() {
+    let path = words_file("messy.txt", "amber \n\nSonar\r\n  handy\n\n");
+    let output = lists(&["normalise", path.to_str().unwrap()]);
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "amber\nhandy\nsonar\n");
+
+    let path = words_file("messy-bad.txt", "amber \n\nam8er\n");
+    let output = lists(&["merge", path.to_str().unwrap()]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("messy-bad.txt: line 3: "));
+}
+
+#[test]
+fn merge_and_diff() {
+    let old = words_file("old.txt", "amber\nsonar");
+    let new = words_file("new.txt", "sonar\nhandy");
+    let merged = words_file("merged.txt", "");
+
+    let output = lists(&[
+        "merge",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+        "--output",
+        merged.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&merged).unwrap(),
+        "amber\nhandy\nsonar\n"
+    );
+
+    let output = lists(&["diff", old.to_str().unwrap(), new.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "-amber\n+handy\n");
+}
+
+#[test]
+fn subset_and_disjoint() {
+    let answers = words_file("answers.txt", "amber\nsonar");
+    let allowed = words_file("allowed.txt", "amber\nhandy");
+    let args = [answers.to_str().unwrap(), allowed.to_str().unwrap()];
+
+    let output = lists(&["subset", args[0], args[1]]);
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "sonar\n");
+
+    let output = lists(&["disjoint", args[0], args[1]]);
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "amber\n");
+
+    let output = lists(&["subset", args[0], args[0]]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn usage_errors() {
+    assert_eq!(lists(&[]).status.code(), Some(2));
+    assert_eq!(lists(&["diff", "one"]).status.code(), Some(2));
+    assert_eq!(lists(&["frobnicate"]).status.code(), Some(2));
+}
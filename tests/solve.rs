@@ -0,0 +1,88 @@
+mod common;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use common::words_file;
+
+fn solve(name: &str, extra: &[&str], stdin: &str) -> String {
+    let words = words_file(
+        &format!("{name}.txt"),
+        "amber\nember\number\nsonar\nhandy\narbor",
+    );
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_kwordle-solve"))
+        .args(["--words", words.to_str().unwrap()])
+        .args(extra)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn text() {
+    let stdout = solve("text", &[], "sonar ....O\nnope\n");
+
+    assert!(stdout.starts_with(
+        "sonar ....O: 2 answers left\n  ember umber\nnext guesses:\n  ember 1.000 bits *\n"
+    ));
+    assert!(stdout.contains("  amber 0.000 bits\n"));
+    assert!(stdout.contains("expected a guess and its pattern"));
+}
+
+#[test]
+fn json() {
+    let stdout = solve(
+        "json",
+        &["--json", "--top", "1", "--only-candidates"],
+        "handy .o...\nzzzzz .....\n",
+    );
+    let lines: Vec<_> = stdout.lines().collect();
+
+    assert_eq!(
+        lines,
+        [
+            r#"{"guess":"handy","pattern":".o...","remaining":2,"candidates":["amber","arbor"],"suggestions":[{"word":"amber","entropy":1.0,"candidate":true}]}"#,
+            r#"{"error":"invalid guess \"zzzzz\": letters [Z, Z, Z, Z, Z] not found in list"}"#,
+        ]
+    );
+}
+
+#[test]
+fn json_candidates_limit() {
+    let stdout = solve(
+        "json-limit",
+        &["--json", "--candidates", "1"],
+        "handy .o...\n",
+    );
+
+    assert!(stdout.starts_with(
+        r#"{"guess":"handy","pattern":".o...","remaining":2,"candidates":["amber"],"#
+    ));
+}
+
+#[test]
+fn answers_not_guessable() {
+    let answers = words_file("overlap-answers.txt", "amber\nzesty");
+    let stdout = solve(
+        "overlap",
+        &["--answers", answers.to_str().unwrap()],
+        "arbor .....\n",
+    );
+
+    assert!(stdout.starts_with("arbor .....: 1 answer left\n  zesty\n"));
+    assert!(stdout.contains("  zesty 0.000 bits *\n"));
+}